@@ -1,8 +1,11 @@
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use color_eyre::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyEventKind};
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use futures::StreamExt;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Position, Rect},
@@ -14,16 +17,27 @@ use ratatui::{
         Color, Modifier, Style, Stylize,
     },
     symbols,
-    text::Line,
+    text::{Line, Span, Text},
     widgets::{
-        Block, Borders, List, ListState, Padding, Paragraph, Scrollbar, ScrollbarState,
-        StatefulWidget, Widget, Wrap,
+        BarChart, Block, Borders, List, ListState, Padding, Paragraph, Scrollbar,
+        ScrollbarState, Sparkline, StatefulWidget, Widget, Wrap,
     },
     DefaultTerminal,
 };
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 use crate::{
-    metadata::{cmp_numeric_lexicographic, RenderMetadata, TensorMetadata},
+    config::{AwqVersion, Bnb4BitQuantType, Config, QuantizationConfig},
+    dequant::{
+        compute_dequant_preview, DequantPreview, DequantRequest, DequantScheme, TensorWindowRef,
+    },
+    highlight::highlight_json,
+    metadata::{insert_tensors, QuantizationType, RenderMetadata, TensorMetadata},
+    repo::MetadataEvent,
+    sort::{cmp_tensors, SortDirection, SortKey},
+    source::MetadataSource,
+    stats::{compute_tensor_stats, TensorRef, TensorStats},
+    summary::summarize,
     InputState,
 };
 
@@ -39,43 +53,151 @@ enum UiState {
     Browse,
     Filter,
     Init,
+    /// The raw, unparsed JSON view: the selected shard's safetensors
+    /// header or the model's `config.json`, syntax-highlighted.
+    Raw,
+    /// The model profiler pane: total parameter count, per-dtype and
+    /// per-layer-type breakdowns, and per-block parameter counts.
+    Summary,
     Quit,
 }
 
+/// Where a selected tensor's value preview is in its lifecycle.
+enum StatsState {
+    Loading,
+    Ready(Option<TensorStats>),
+    Failed,
+}
+
+/// Where a selected `qweight` tensor's dequantization preview is in its
+/// lifecycle.
+enum DequantPreviewState {
+    Loading,
+    Ready(Option<DequantPreview>),
+    Failed,
+}
+
+/// Which document [`UiState::Raw`] is currently showing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RawKind {
+    Header,
+    Config,
+}
+
+/// A key into the raw-JSON cache: either a shard's header (one per
+/// checkpoint filename) or the model's `config.json` (one per app).
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum RawKey {
+    Header(String),
+    Config,
+}
+
+/// Where a raw JSON document's fetch is in its lifecycle.
+enum RawState {
+    Loading,
+    Ready(Option<String>),
+    Failed,
+}
+
 pub struct App {
+    config: Config,
     cursor_position: Option<Position>,
+    /// Per-`qweight`-tensor dequantization preview, computed lazily and
+    /// cached by name so re-selecting an already-previewed tensor is
+    /// instant.
+    dequant: HashMap<String, DequantPreviewState>,
+    dequant_tx: UnboundedSender<(String, Result<Option<DequantPreview>>)>,
+    dequant_rx: UnboundedReceiver<(String, Result<Option<DequantPreview>>)>,
     matcher: SkimMatcherV2,
+    /// Shards resolved so far and shards expected in total, for the
+    /// loading indicator in the footer. `None` once loading has finished.
+    progress: Option<(usize, usize)>,
+    /// Which raw document [`UiState::Raw`] is showing.
+    raw_kind: RawKind,
+    /// Raw JSON text, fetched lazily and cached by [`RawKey`] so toggling
+    /// back and forth between header and config doesn't re-fetch.
+    raw: HashMap<RawKey, RawState>,
+    raw_scroll: u16,
+    raw_tx: UnboundedSender<(RawKey, Result<Option<String>>)>,
+    raw_rx: UnboundedReceiver<(RawKey, Result<Option<String>>)>,
+    source: Arc<dyn MetadataSource>,
+    /// Per-tensor value preview, computed lazily and cached by name so
+    /// re-selecting an already-previewed tensor is instant.
+    stats: HashMap<String, StatsState>,
+    stats_tx: UnboundedSender<(String, Result<Option<TensorStats>>)>,
+    stats_rx: UnboundedReceiver<(String, Result<Option<TensorStats>>)>,
     tensor_names: Vec<String>,
     tensors: HashMap<String, TensorMetadata>,
+    /// Set whenever `tensors` changes so the next frame recomputes
+    /// `tensor_names`, even outside of [`UiState::Filter`].
+    tensors_dirty: bool,
     tensor_list: List<'static>,
     tensor_state: ListState,
     tensor_scrollbar_state: ScrollbarState,
     state: UiState,
     filter_state: InputState,
+    sort_key: SortKey,
+    sort_direction: SortDirection,
+    /// Set whenever `sort_key`/`sort_direction` changes, so the next frame
+    /// re-sorts `tensor_names` even though its length hasn't changed (the
+    /// usual trigger for a recompute).
+    sort_dirty: bool,
 }
 
 impl App {
-    /// Construct a new instance of [`App`].
-    pub fn new(tensors: HashMap<String, TensorMetadata>) -> Self {
-        let scroll_len = tensors.len();
+    /// Construct a new instance of [`App`]. Tensors are populated later via
+    /// [`App::handle_metadata_event`] as shards resolve.
+    pub fn new(config: Config, source: Arc<dyn MetadataSource>) -> Self {
+        let (stats_tx, stats_rx) = mpsc::unbounded_channel();
+        let (raw_tx, raw_rx) = mpsc::unbounded_channel();
+        let (dequant_tx, dequant_rx) = mpsc::unbounded_channel();
 
         Self {
+            config,
             cursor_position: None,
+            dequant: Default::default(),
+            dequant_tx,
+            dequant_rx,
             filter_state: Default::default(),
             matcher: Default::default(),
+            progress: None,
+            raw_kind: RawKind::Header,
+            raw: Default::default(),
+            raw_scroll: 0,
+            raw_tx,
+            raw_rx,
+            source,
+            stats: Default::default(),
+            stats_tx,
+            stats_rx,
             tensor_list: Default::default(),
             tensor_names: Default::default(),
-            tensors,
+            tensors: Default::default(),
+            tensors_dirty: true,
             tensor_state: Default::default(),
-            tensor_scrollbar_state: ScrollbarState::new(scroll_len),
+            tensor_scrollbar_state: ScrollbarState::new(0),
             state: UiState::Init,
+            sort_key: Default::default(),
+            sort_direction: Default::default(),
+            sort_dirty: false,
         }
     }
 
     /// Run the application's main loop.
-    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
+    ///
+    /// The terminal is redrawn whenever a key is pressed or a shard
+    /// resolves, whichever comes first, so the tensor list fills in live
+    /// while the checkpoint is still downloading instead of waiting for
+    /// `events` to close.
+    pub async fn run(
+        mut self,
+        mut terminal: DefaultTerminal,
+        mut events: UnboundedReceiver<Result<MetadataEvent>>,
+    ) -> Result<()> {
+        let mut term_events = EventStream::new();
+
         while !matches!(self.state, UiState::Quit) {
-            if matches!(self.state, UiState::Init | UiState::Filter) {
+            if self.tensors_dirty || matches!(self.state, UiState::Init | UiState::Filter) {
                 self.update_tensor_names();
             }
 
@@ -83,20 +205,244 @@ impl App {
                 self.state = UiState::Browse;
             }
 
+            self.ensure_stats_requested();
+            self.ensure_raw_requested();
+            self.ensure_dequant_requested();
+
             terminal.draw(|frame| {
                 frame.render_widget(&mut self, frame.area());
                 if let Some(cursor_position) = self.cursor_position {
                     frame.set_cursor_position(cursor_position);
                 }
             })?;
-            if let Event::Key(key) = event::read()? {
-                self.handle_key(key);
-            };
+
+            tokio::select! {
+                term_event = term_events.next() => {
+                    if let Some(Ok(Event::Key(key))) = term_event {
+                        self.handle_key(key);
+                    }
+                }
+                metadata_event = events.recv() => {
+                    match metadata_event {
+                        Some(Ok(event)) => self.handle_metadata_event(event),
+                        Some(Err(err)) => return Err(err),
+                        None => self.progress = None,
+                    }
+                }
+                stats_event = self.stats_rx.recv() => {
+                    if let Some((name, result)) = stats_event {
+                        self.stats.insert(
+                            name,
+                            match result {
+                                Ok(stats) => StatsState::Ready(stats),
+                                Err(_) => StatsState::Failed,
+                            },
+                        );
+                    }
+                }
+                raw_event = self.raw_rx.recv() => {
+                    if let Some((key, result)) = raw_event {
+                        self.raw.insert(
+                            key,
+                            match result {
+                                Ok(text) => RawState::Ready(text),
+                                Err(_) => RawState::Failed,
+                            },
+                        );
+                    }
+                }
+                dequant_event = self.dequant_rx.recv() => {
+                    if let Some((name, result)) = dequant_event {
+                        self.dequant.insert(
+                            name,
+                            match result {
+                                Ok(preview) => DequantPreviewState::Ready(preview),
+                                Err(_) => DequantPreviewState::Failed,
+                            },
+                        );
+                    }
+                }
+            }
         }
         Ok(())
     }
 
+    /// Kick off a background fetch of the selected tensor's value preview
+    /// if it hasn't been requested yet, so the render loop never blocks on
+    /// the RANGE read that backs it.
+    fn ensure_stats_requested(&mut self) {
+        let Some(i) = self.tensor_state.selected() else {
+            return;
+        };
+        let Some(name) = self.tensor_names.get(i).cloned() else {
+            return;
+        };
+        if self.stats.contains_key(&name) {
+            return;
+        }
+        let Some(metadata) = self.tensors.get(&name) else {
+            return;
+        };
+
+        let tensor_ref = TensorRef {
+            checkpoint: metadata.checkpoint.clone(),
+            header_size: metadata.header_size,
+            data_offsets: metadata.tensor_info.data_offsets,
+            dtype: metadata.tensor_info.dtype,
+        };
+
+        self.stats.insert(name.clone(), StatsState::Loading);
+
+        let source = Arc::clone(&self.source);
+        let tx = self.stats_tx.clone();
+        tokio::spawn(async move {
+            let result = compute_tensor_stats(source.as_ref(), &tensor_ref).await;
+            let _ = tx.send((name, result));
+        });
+    }
+
+    /// Kick off a background fetch of whichever raw document
+    /// [`Self::raw_kind`] currently points at, if it hasn't been fetched
+    /// yet, so opening [`UiState::Raw`] never blocks the render loop.
+    fn ensure_raw_requested(&mut self) {
+        if self.state != UiState::Raw {
+            return;
+        }
+
+        match self.raw_kind {
+            RawKind::Header => {
+                let Some(i) = self.tensor_state.selected() else {
+                    return;
+                };
+                let Some(name) = self.tensor_names.get(i) else {
+                    return;
+                };
+                let Some(metadata) = self.tensors.get(name) else {
+                    return;
+                };
+
+                let key = RawKey::Header(metadata.checkpoint.clone());
+                if self.raw.contains_key(&key) {
+                    return;
+                }
+                let checkpoint = metadata.checkpoint.clone();
+                let header_size = metadata.header_size;
+                self.raw.insert(key.clone(), RawState::Loading);
+
+                let source = Arc::clone(&self.source);
+                let tx = self.raw_tx.clone();
+                tokio::spawn(async move {
+                    let result = fetch_raw_header(source.as_ref(), &checkpoint, header_size).await;
+                    let _ = tx.send((key, result));
+                });
+            }
+            RawKind::Config => {
+                if self.raw.contains_key(&RawKey::Config) {
+                    return;
+                }
+                self.raw.insert(RawKey::Config, RawState::Loading);
+
+                let source = Arc::clone(&self.source);
+                let tx = self.raw_tx.clone();
+                tokio::spawn(async move {
+                    let result = source.get_raw_config().await;
+                    let _ = tx.send((RawKey::Config, result));
+                });
+            }
+        }
+    }
+
+    /// Kick off a background fetch of the selected tensor's dequantization
+    /// preview if it hasn't been requested yet, so the render loop never
+    /// blocks on the RANGE reads that back it.
+    fn ensure_dequant_requested(&mut self) {
+        let Some(i) = self.tensor_state.selected() else {
+            return;
+        };
+        let Some(name) = self.tensor_names.get(i).cloned() else {
+            return;
+        };
+        if self.dequant.contains_key(&name) {
+            return;
+        }
+        let Some(request) = self.build_dequant_request(&name) else {
+            return;
+        };
+
+        self.dequant.insert(name.clone(), DequantPreviewState::Loading);
+
+        let source = Arc::clone(&self.source);
+        let tx = self.dequant_tx.clone();
+        tokio::spawn(async move {
+            let result = compute_dequant_preview(source.as_ref(), request).await;
+            let _ = tx.send((name, result));
+        });
+    }
+
+    /// Build the inputs [`compute_dequant_preview`] needs for `qweight_name`,
+    /// if it names a `qweight` tensor with a quantization scheme this app
+    /// knows how to unpack and a sibling `scales`/`qzeros` pair.
+    fn build_dequant_request(&self, qweight_name: &str) -> Option<DequantRequest> {
+        let (prefix, suffix) = qweight_name.rsplit_once('.')?;
+        if suffix != "qweight" {
+            return None;
+        }
+
+        let qweight = self.tensors.get(qweight_name)?;
+        let quantization = qweight.quantization.as_ref()?;
+        let (scheme, desc_act) = match quantization.qtype() {
+            QuantizationType::Awq {
+                version: AwqVersion::Gemm,
+            } => (DequantScheme::Awq, false),
+            QuantizationType::Gptq { desc_act, .. } => (DequantScheme::Gptq, *desc_act),
+            _ => return None,
+        };
+
+        let scales = self.tensors.get(&format!("{prefix}.scales"))?;
+        let qzeros = self.tensors.get(&format!("{prefix}.qzeros"))?;
+        // `desc_act` checkpoints need `g_idx` to know each row's group; bail
+        // out rather than show a preview computed with the wrong groups if
+        // it's missing.
+        let g_idx = if desc_act {
+            Some(tensor_window_ref(
+                self.tensors.get(&format!("{prefix}.g_idx"))?,
+            ))
+        } else {
+            None
+        };
+
+        Some(DequantRequest {
+            qweight: tensor_window_ref(qweight),
+            qzeros: tensor_window_ref(qzeros),
+            scales: tensor_window_ref(scales),
+            scheme,
+            n_bits: quantization.dtype().n_bits().get(),
+            group_size: quantization.group_size(),
+            g_idx,
+        })
+    }
+
+    /// Merge a shard into the tensor list or update loading progress, as
+    /// it streams in from [`crate::repo::SafeTensorsRepo::get_checkpoint_metadatas`].
+    fn handle_metadata_event(&mut self, event: MetadataEvent) {
+        match event {
+            MetadataEvent::Progress { done, total } => {
+                self.progress = if done == total {
+                    None
+                } else {
+                    Some((done, total))
+                };
+            }
+            MetadataEvent::Checkpoint(checkpoint) => {
+                insert_tensors(&mut self.tensors, &self.config, &checkpoint);
+                self.tensors_dirty = true;
+            }
+        }
+    }
+
     fn update_tensor_names(&mut self) {
+        self.tensors_dirty = false;
+
         let new_tensor_names: Vec<String> = self
             .tensors
             .keys()
@@ -108,14 +454,17 @@ impl App {
             .map(String::clone)
             .collect();
 
-        if new_tensor_names.len() == self.tensor_names.len() {
+        if !self.sort_dirty && new_tensor_names.len() == self.tensor_names.len() {
             return;
         }
+        self.sort_dirty = false;
 
         self.tensor_names = new_tensor_names;
 
+        let tensors = &self.tensors;
+        let (sort_key, sort_direction) = (self.sort_key, self.sort_direction);
         self.tensor_names
-            .sort_by(|k1, k2| cmp_numeric_lexicographic(k1, k2));
+            .sort_by(|k1, k2| cmp_tensors(tensors, sort_key, sort_direction, k1, k2));
         self.tensor_scrollbar_state = self
             .tensor_scrollbar_state
             .content_length(self.tensor_names.len());
@@ -143,10 +492,51 @@ impl App {
                 KeyCode::Char('g') | KeyCode::Home => self.select_first(),
                 KeyCode::Char('G') | KeyCode::End => self.select_last(),
                 KeyCode::Char('/') => self.state = UiState::Filter,
+                KeyCode::Char('s') => self.state = UiState::Summary,
+                KeyCode::Char('r') => {
+                    self.raw_scroll = 0;
+                    self.state = UiState::Raw;
+                }
+                KeyCode::Char('o') => {
+                    self.sort_key = self.sort_key.next();
+                    self.sort_dirty = true;
+                }
+                KeyCode::Char('O') => {
+                    self.sort_direction = self.sort_direction.toggle();
+                    self.sort_dirty = true;
+                }
                 KeyCode::PageDown => self.page_down(),
                 KeyCode::PageUp => self.page_up(),
                 _ => {}
             },
+            UiState::Summary => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('s') => {
+                    self.state = UiState::Browse
+                }
+                _ => {}
+            },
+            UiState::Raw => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('r') => {
+                    self.state = UiState::Browse
+                }
+                KeyCode::Tab => {
+                    self.raw_kind = match self.raw_kind {
+                        RawKind::Header => RawKind::Config,
+                        RawKind::Config => RawKind::Header,
+                    };
+                    self.raw_scroll = 0;
+                }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.raw_scroll = self.raw_scroll.saturating_add(1)
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.raw_scroll = self.raw_scroll.saturating_sub(1)
+                }
+                KeyCode::PageDown => self.raw_scroll = self.raw_scroll.saturating_add(10),
+                KeyCode::PageUp => self.raw_scroll = self.raw_scroll.saturating_sub(10),
+                KeyCode::Char('g') | KeyCode::Home => self.raw_scroll = 0,
+                _ => {}
+            },
             UiState::Filter => match key.code {
                 KeyCode::Enter => self.state = UiState::Browse,
                 KeyCode::Esc => self.state = UiState::Browse,
@@ -182,18 +572,26 @@ impl App {
     }
 
     fn render_footer(&mut self, area: Rect, buf: &mut Buffer) {
-        match self.state {
-            UiState::Browse => {
-                Paragraph::new("Use ↓↑ to move, g/G to go top/bottom, forward slash (/) to filter.")
-                    .centered()
-                    .render(area, buf)
+        let help = match self.state {
+            UiState::Browse => format!(
+                "Use ↓↑ to move, g/G to go top/bottom, forward slash (/) to filter, s for model summary, r for raw JSON, o to sort by {} ({}), O to flip order.",
+                self.sort_key, self.sort_direction
+            ),
+            UiState::Filter => "Use Esc or Enter to confirm filter.".to_string(),
+            UiState::Summary => "Use Esc or s to return to the tensor list.".to_string(),
+            UiState::Raw => {
+                "Use ↓↑ to scroll, Tab to switch header/config, Esc or r to return.".to_string()
             }
-            UiState::Filter => Paragraph::new("Use Esc or Enter to confirm filter.")
-                .centered()
-                .render(area, buf),
             UiState::Init => unreachable!(),
             UiState::Quit => unreachable!(),
-        }
+        };
+
+        let text = match self.progress {
+            Some((done, total)) => format!("Loading shards ({done}/{total})… {help}"),
+            None => help,
+        };
+
+        Paragraph::new(text).centered().render(area, buf);
     }
 
     fn render_header(&self, area: Rect, buf: &mut Buffer) {
@@ -229,17 +627,35 @@ impl App {
     }
 
     fn render_selected_item(&mut self, area: Rect, buf: &mut Buffer) {
-        let info = if let Some(i) = self.tensor_state.selected() {
-            let name = &self.tensor_names[i];
-            let metadata = &self.tensors[name];
-            let mut info = Vec::new();
-            metadata.render_metadata(metadata, &mut info);
-            info
-        } else {
-            vec![Line::raw("Nothing selected...")]
-            //"Nothing selected...".to_string()
+        let selected_name = self
+            .tensor_state
+            .selected()
+            .and_then(|i| self.tensor_names.get(i));
+
+        let mut info = match selected_name {
+            Some(name) => {
+                let metadata = &self.tensors[name];
+                let mut info = Vec::new();
+                metadata.render_metadata(metadata, &mut info);
+                info
+            }
+            None => vec![Line::raw("Nothing selected...")],
         };
 
+        let histogram = selected_name
+            .and_then(|name| self.stats.get(name))
+            .and_then(|stats| {
+                render_stats(stats, &mut info);
+                match stats {
+                    StatsState::Ready(Some(stats)) => Some(&stats.histogram),
+                    _ => None,
+                }
+            });
+
+        if let Some(dequant) = selected_name.and_then(|name| self.dequant.get(name)) {
+            render_dequant_preview(dequant, &mut info);
+        }
+
         // We show the list item's info under the list in this paragraph
         let block = Block::new()
             .title(Line::raw("Metadata").centered())
@@ -249,12 +665,31 @@ impl App {
             .bg(NORMAL_ROW_BG)
             .padding(Padding::horizontal(1));
 
-        // We can now render the item info
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let (text_area, histogram_area) = match histogram {
+            Some(_) => {
+                let [text_area, histogram_area] =
+                    Layout::vertical([Constraint::Fill(1), Constraint::Length(9)]).areas(inner);
+                (text_area, Some(histogram_area))
+            }
+            None => (inner, None),
+        };
+
         Paragraph::new(info)
-            .block(block)
             .fg(TEXT_FG_COLOR)
             .wrap(Wrap { trim: false })
-            .render(area, buf);
+            .render(text_area, buf);
+
+        if let (Some(histogram), Some(histogram_area)) = (histogram, histogram_area) {
+            let data: Vec<u64> = histogram.iter().map(|&count| count as u64).collect();
+            Sparkline::default()
+                .block(Block::new().title(Line::raw("Value histogram")))
+                .style(Style::new().magenta())
+                .data(&data)
+                .render(histogram_area, buf);
+        }
     }
 
     fn page_down(&mut self) {
@@ -294,6 +729,295 @@ impl App {
         self.tensor_state.select_previous();
         self.tensor_scrollbar_state.prev();
     }
+
+    /// Render the model profiler pane: a text summary of parameter/byte
+    /// counts by dtype and layer type, plus a bar chart of per-block
+    /// parameter counts. Recomputed fresh on every render rather than
+    /// cached, since it's cheap relative to a single frame.
+    fn render_summary(&mut self, area: Rect, buf: &mut Buffer) {
+        let summary = summarize(&self.tensors, &self.config);
+
+        let block = Block::new()
+            .title(Line::raw("Model summary").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(TODO_HEADER_STYLE)
+            .bg(NORMAL_ROW_BG)
+            .padding(Padding::horizontal(1));
+
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let [text_area, chart_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(9)]).areas(inner);
+
+        let field_style = Style::new().magenta();
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Total parameters: ", field_style),
+                Span::raw(summary.total_params.to_string()),
+            ]),
+            Line::from(vec![
+                Span::styled("Total bytes: ", field_style),
+                Span::raw(summary.total_bytes.to_string()),
+            ]),
+            Line::default(),
+            Line::from(vec![Span::styled(
+                "Bytes by dtype",
+                Style::new().blue().underlined(),
+            )]),
+        ];
+        for (dtype, bytes) in &summary.bytes_by_dtype {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{dtype}: "), field_style),
+                Span::raw(bytes.to_string()),
+            ]));
+        }
+
+        lines.push(Line::default());
+        lines.push(Line::from(vec![Span::styled(
+            "Parameters by layer type",
+            Style::new().blue().underlined(),
+        )]));
+        for (layer_type, count) in &summary.params_by_layer_type {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{layer_type}: "), field_style),
+                Span::raw(count.to_string()),
+            ]));
+        }
+
+        if let Some(quantization_config) = &self.config.quantization_config {
+            let (method, bits, group_size): (&str, Option<NonZeroUsize>, Option<usize>) =
+                match quantization_config {
+                    QuantizationConfig::Awq {
+                        bits, group_size, ..
+                    } => ("AWQ", Some(*bits), Some(*group_size)),
+                    QuantizationConfig::Gptq {
+                        bits, group_size, ..
+                    } => ("GPTQ", Some(*bits), Some(*group_size)),
+                    QuantizationConfig::Fp8 { .. } => ("FP8", None, None),
+                    QuantizationConfig::Bitsandbytes { quant_type, .. } => (
+                        match quant_type {
+                            Bnb4BitQuantType::Nf4 => "bitsandbytes (NF4)",
+                            Bnb4BitQuantType::Fp4 => "bitsandbytes (FP4)",
+                        },
+                        None,
+                        None,
+                    ),
+                };
+
+            lines.push(Line::default());
+            lines.push(Line::from(vec![Span::styled(
+                "Quantization",
+                Style::new().blue().underlined(),
+            )]));
+            lines.push(Line::from(vec![
+                Span::styled("Method: ", field_style),
+                Span::raw(method),
+            ]));
+            if let Some(bits) = bits {
+                lines.push(Line::from(vec![
+                    Span::styled("Bits: ", field_style),
+                    Span::raw(bits.to_string()),
+                ]));
+            }
+            if let Some(group_size) = group_size {
+                lines.push(Line::from(vec![
+                    Span::styled("Group size: ", field_style),
+                    Span::raw(group_size.to_string()),
+                ]));
+            }
+        }
+
+        Paragraph::new(lines)
+            .fg(TEXT_FG_COLOR)
+            .wrap(Wrap { trim: false })
+            .render(text_area, buf);
+
+        let block_labels: Vec<String> = summary
+            .params_by_block
+            .keys()
+            .map(|block| block.to_string())
+            .collect();
+        let bars: Vec<(&str, u64)> = block_labels
+            .iter()
+            .map(String::as_str)
+            .zip(summary.params_by_block.values().copied())
+            .collect();
+
+        BarChart::default()
+            .block(Block::new().title(Line::raw("Parameters by block")))
+            .bar_style(Style::new().magenta())
+            .data(&bars)
+            .render(chart_area, buf);
+    }
+
+    /// Render the raw, unparsed JSON view: whichever document
+    /// [`Self::raw_kind`] points at, syntax-highlighted and scrolled by
+    /// [`Self::raw_scroll`] lines.
+    fn render_raw(&mut self, area: Rect, buf: &mut Buffer) {
+        let title = match self.raw_kind {
+            RawKind::Header => "Raw header (Tab for config.json)",
+            RawKind::Config => "Raw config.json (Tab for header)",
+        };
+
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(TODO_HEADER_STYLE)
+            .bg(NORMAL_ROW_BG)
+            .padding(Padding::horizontal(1));
+
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let key = match self.raw_kind {
+            RawKind::Header => self
+                .tensor_state
+                .selected()
+                .and_then(|i| self.tensor_names.get(i))
+                .and_then(|name| self.tensors.get(name))
+                .map(|metadata| RawKey::Header(metadata.checkpoint.clone())),
+            RawKind::Config => Some(RawKey::Config),
+        };
+
+        let text = match key.and_then(|key| self.raw.get(&key)) {
+            None => Text::raw("Nothing selected..."),
+            Some(RawState::Loading) => Text::raw("Loading…"),
+            Some(RawState::Failed) => Text::raw("Could not read raw JSON."),
+            Some(RawState::Ready(None)) => Text::raw("No config.json for this checkpoint."),
+            Some(RawState::Ready(Some(json))) => {
+                highlight_json(json).unwrap_or_else(|_| Text::raw(json.clone()))
+            }
+        };
+
+        Paragraph::new(text)
+            .scroll((self.raw_scroll, 0))
+            .render(inner, buf);
+    }
+}
+
+/// Append the value-preview section (or its loading/error placeholder) to
+/// the metadata panel's lines.
+fn render_stats(stats: &StatsState, lines: &mut Vec<Line>) {
+    let field_style = Style::new().magenta();
+
+    match stats {
+        StatsState::Loading => {
+            lines.push(Line::default());
+            lines.push(Line::raw("Computing value preview…"));
+        }
+        StatsState::Failed => {
+            lines.push(Line::default());
+            lines.push(Line::raw("Could not read tensor data."));
+        }
+        // Opaque/quantized dtypes aren't decoded; say nothing rather than
+        // clutter the panel with a preview that will never appear.
+        StatsState::Ready(None) => {}
+        StatsState::Ready(Some(stats)) => {
+            lines.extend([
+                Line::default(),
+                Line::from(vec![Span::styled(
+                    "Value preview",
+                    Style::new().blue().underlined(),
+                )]),
+                Line::default(),
+                Line::from(vec![
+                    Span::styled("Count: ", field_style),
+                    Span::raw(stats.count.to_string()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Min: ", field_style),
+                    Span::raw(format!("{:.6}", stats.min)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Max: ", field_style),
+                    Span::raw(format!("{:.6}", stats.max)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Mean: ", field_style),
+                    Span::raw(format!("{:.6}", stats.mean)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Std: ", field_style),
+                    Span::raw(format!("{:.6}", stats.std)),
+                ]),
+                Line::from(vec![
+                    Span::styled("NaN count: ", field_style),
+                    Span::raw(stats.nan_count.to_string()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Inf count: ", field_style),
+                    Span::raw(stats.inf_count.to_string()),
+                ]),
+            ]);
+
+            if stats.sampled {
+                lines.push(Line::raw(
+                    "(sampled from the tensor's leading bytes; too large to preview in full)",
+                ));
+            }
+        }
+    }
+}
+
+/// Append the dequantization-preview section (or its loading/error
+/// placeholder) to the metadata panel's lines.
+fn render_dequant_preview(state: &DequantPreviewState, lines: &mut Vec<Line>) {
+    match state {
+        DequantPreviewState::Loading => {
+            lines.push(Line::default());
+            lines.push(Line::raw("Computing dequantization preview…"));
+        }
+        DequantPreviewState::Failed => {
+            lines.push(Line::default());
+            lines.push(Line::raw("Could not read tensor data."));
+        }
+        // Schemes this module doesn't unpack (FP8, AWQ-GEMV, ...) aren't
+        // previewed; say nothing rather than clutter the panel.
+        DequantPreviewState::Ready(None) => {}
+        DequantPreviewState::Ready(Some(preview)) => {
+            lines.push(Line::default());
+            lines.push(Line::from(vec![Span::styled(
+                "Dequantized preview (top-left corner)",
+                Style::new().blue().underlined(),
+            )]));
+            lines.push(Line::default());
+            for row in preview.values.chunks_exact(preview.cols) {
+                let text = row
+                    .iter()
+                    .map(|v| format!("{v:>10.4}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                lines.push(Line::raw(text));
+            }
+        }
+    }
+}
+
+/// Build the owned, background-task-friendly fields [`compute_dequant_preview`]
+/// needs for one tensor.
+fn tensor_window_ref(metadata: &TensorMetadata) -> TensorWindowRef {
+    TensorWindowRef {
+        checkpoint: metadata.checkpoint.clone(),
+        header_size: metadata.header_size,
+        data_offsets: metadata.tensor_info.data_offsets,
+        shape: metadata.tensor_info.shape.clone(),
+        dtype: metadata.tensor_info.dtype,
+    }
+}
+
+/// RANGE-fetch the raw, unparsed JSON header of `checkpoint`, using its
+/// already-known `header_size` rather than re-deriving it from the
+/// 8-byte length prefix.
+async fn fetch_raw_header(
+    source: &dyn MetadataSource,
+    checkpoint: &str,
+    header_size: u64,
+) -> Result<Option<String>> {
+    let bytes = source.read_tensor_bytes(checkpoint, 8..8 + header_size).await?;
+    Ok(Some(String::from_utf8(bytes)?))
 }
 
 impl Widget for &mut App {
@@ -305,6 +1029,20 @@ impl Widget for &mut App {
         ])
         .areas(area);
 
+        self.render_header(header_area, buf);
+
+        if self.state == UiState::Summary {
+            self.render_summary(main_area, buf);
+            self.render_footer(footer_area, buf);
+            return;
+        }
+
+        if self.state == UiState::Raw {
+            self.render_raw(main_area, buf);
+            self.render_footer(footer_area, buf);
+            return;
+        }
+
         let [select_area, detail_area] =
             Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(main_area);
 
@@ -314,11 +1052,12 @@ impl Widget for &mut App {
                     .areas(select_area),
                 UiState::Filter => Layout::vertical([Constraint::Fill(1), Constraint::Length(3)])
                     .areas(select_area),
+                UiState::Summary => unreachable!("handled above"),
+                UiState::Raw => unreachable!("handled above"),
                 UiState::Init => unreachable!(),
                 UiState::Quit => unreachable!(),
             };
 
-        self.render_header(header_area, buf);
         self.render_list(list_area, buf);
         self.render_filter(filter_area, buf);
         self.render_selected_item(detail_area, buf);
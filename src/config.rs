@@ -3,7 +3,7 @@ use std::num::NonZeroUsize;
 use serde::{Deserialize, Deserializer};
 use serde_json::Value;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct Config {
     pub model_type: String,
 
@@ -29,6 +29,23 @@ pub enum QuantizationConfig {
         static_groups: bool,
         sym: bool,
     },
+    Fp8 {
+        #[serde(default)]
+        activation_scheme: Option<String>,
+    },
+    Bitsandbytes {
+        #[serde(rename = "bnb_4bit_quant_type")]
+        quant_type: Bnb4BitQuantType,
+        #[serde(rename = "bnb_4bit_use_double_quant", default)]
+        use_double_quant: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bnb4BitQuantType {
+    Nf4,
+    Fp4,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize)]
@@ -0,0 +1,403 @@
+use color_eyre::eyre::Result;
+use safetensors::Dtype;
+
+use crate::source::MetadataSource;
+use crate::stats::decode;
+
+/// How many rows/columns of the dequantized weight matrix to reconstruct.
+/// Large enough to sanity-check the packing and scale/zero-point math,
+/// small enough that it's a handful of RANGE reads rather than decoding
+/// the whole (potentially huge) tensor.
+const PREVIEW_ROWS: usize = 4;
+const PREVIEW_COLS: usize = 4;
+
+/// Both schemes this module dequantizes pack values into 32-bit words.
+const PACK_WORD_BITS: usize = 32;
+
+/// Lane order AWQ's GEMM kernel packs four-bit nibbles into an int32 word
+/// in, forced by how the kernel vectorizes dequantization (see
+/// `autoawq`/`vllm`'s `awq_dequantize` kernels). Sequential bit-shifting,
+/// the natural guess, produces a shuffled weight matrix for this format.
+const AWQ_NIBBLE_ORDER: [usize; 8] = [0, 2, 4, 6, 1, 3, 5, 7];
+
+/// The handful of fields [`compute_dequant_preview`] needs to fetch and
+/// unpack one tensor's raw bytes, owned rather than borrowed from
+/// [`crate::metadata::TensorMetadata`] so the fetch can run in a
+/// background task independent of the render loop.
+#[derive(Clone, Debug)]
+pub struct TensorWindowRef {
+    pub checkpoint: String,
+    pub header_size: u64,
+    pub data_offsets: [usize; 2],
+    pub shape: Vec<usize>,
+    pub dtype: Dtype,
+}
+
+/// Which packed layout a `qweight`/`qzeros` pair uses. AWQ and GPTQ pack
+/// the same bit width into the same 32-bit word, but along different
+/// axes, so this is the only thing that needs to vary per scheme.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DequantScheme {
+    /// AWQ's GEMM kernel: `qweight` packs along the output-feature
+    /// (column) axis, with a shuffled nibble order.
+    Awq,
+    /// GPTQ: `qweight` packs along the input-feature (row) axis,
+    /// sequentially; `qzeros` still packs along columns.
+    Gptq,
+}
+
+/// Everything [`compute_dequant_preview`] needs for one `qweight` tensor
+/// and its paired `scales`/`qzeros`.
+pub struct DequantRequest {
+    pub qweight: TensorWindowRef,
+    pub qzeros: TensorWindowRef,
+    pub scales: TensorWindowRef,
+    pub scheme: DequantScheme,
+    pub n_bits: usize,
+    pub group_size: usize,
+    /// The checkpoint's `g_idx` tensor, if this is a `desc_act` GPTQ
+    /// checkpoint: row `i`'s group is `g_idx[i]` rather than
+    /// `i / group_size`, since `desc_act` reorders rows by activation
+    /// importance instead of leaving them in group order. `None` for
+    /// every other case (including non-`desc_act` GPTQ).
+    pub g_idx: Option<TensorWindowRef>,
+}
+
+/// The top-left corner of a dequantized weight matrix, reconstructed from
+/// a quantized tensor's raw bytes plus its scales/zero-points.
+pub struct DequantPreview {
+    pub rows: usize,
+    pub cols: usize,
+    pub values: Vec<f32>,
+}
+
+#[derive(Clone, Copy)]
+enum Packing {
+    /// Several values are packed into one stored word along the row axis
+    /// (GPTQ `qweight`): unpacking one logical row requires reading a
+    /// different packed word than its row neighbours.
+    Rows,
+    /// Several values are packed into one stored word along the column
+    /// axis (AWQ `qweight`/`qzeros`, GPTQ `qzeros`): unpacking one logical
+    /// column requires shifting a different lane out of the same packed
+    /// word its column neighbours come from.
+    Columns,
+}
+
+/// Reconstruct the top-left corner of a `qweight` tensor's dequantized
+/// matrix from its raw bytes and paired `scales`/`qzeros`.
+pub async fn compute_dequant_preview(
+    source: &dyn MetadataSource,
+    request: DequantRequest,
+) -> Result<Option<DequantPreview>> {
+    let n_packed = PACK_WORD_BITS / request.n_bits;
+    let weight_packing = match request.scheme {
+        DequantScheme::Awq => Packing::Columns,
+        DequantScheme::Gptq => Packing::Rows,
+    };
+
+    let Some((&packed_rows, &packed_cols)) = request
+        .qweight
+        .shape
+        .first()
+        .zip(request.qweight.shape.get(1))
+    else {
+        // Not a rank-2 tensor; nothing sensible to preview.
+        return Ok(None);
+    };
+    let (rows, cols) = match weight_packing {
+        Packing::Rows => (packed_rows * n_packed, packed_cols),
+        Packing::Columns => (packed_rows, packed_cols * n_packed),
+    };
+    let rows = rows.min(PREVIEW_ROWS);
+    let cols = cols.min(PREVIEW_COLS);
+
+    let group_size = request.group_size.max(1);
+
+    // `g_idx[i]` gives row `i`'s group directly for `desc_act` checkpoints,
+    // where rows are reordered by activation importance rather than left
+    // in group order; otherwise the group is just `i / group_size`. Either
+    // way, the groups referenced by the first `rows` rows never exceed the
+    // end of the previewed window, so `group_rows` is sized to cover
+    // whichever one applies.
+    let group_indices = match &request.g_idx {
+        Some(g_idx) => {
+            let indices = read_group_indices(source, g_idx, rows).await?;
+            if indices.len() < rows {
+                return Ok(None);
+            }
+            Some(indices)
+        }
+        None => None,
+    };
+    let group_rows = match &group_indices {
+        Some(indices) => indices.iter().copied().max().unwrap_or(0) + 1,
+        None => (rows - 1) / group_size + 1,
+    };
+
+    let packed_weights = read_packed_window(
+        source,
+        &request.qweight,
+        weight_packing,
+        rows,
+        cols,
+        request.n_bits,
+        n_packed,
+        packed_cols,
+        request.scheme,
+    )
+    .await?;
+
+    // Zero-points are always packed along the output-feature (column)
+    // axis, one group per row, regardless of how the weights themselves
+    // are packed.
+    let Some(&qzeros_cols) = request.qzeros.shape.get(1) else {
+        return Ok(None);
+    };
+    let packed_zero_points = read_packed_window(
+        source,
+        &request.qzeros,
+        Packing::Columns,
+        group_rows,
+        cols,
+        request.n_bits,
+        n_packed,
+        qzeros_cols,
+        request.scheme,
+    )
+    .await?;
+
+    let scale_values = read_scale_window(source, &request.scales, group_rows, cols).await?;
+    if scale_values.len() < group_rows * cols {
+        return Ok(None);
+    }
+
+    let mut values = Vec::with_capacity(rows * cols);
+    for r in 0..rows {
+        let group = match &group_indices {
+            Some(indices) => indices[r],
+            None => r / group_size,
+        };
+        for c in 0..cols {
+            let scale = scale_values[group * cols + c];
+            let raw = packed_weights[r * cols + c] as f32;
+
+            // `qzeros` is read and subtracted unconditionally: AWQ and
+            // GPTQ both always center the raw value on its zero-point
+            // before scaling. Whether the checkpoint is "symmetric"
+            // (GPTQ's `sym`) only describes how that zero-point was
+            // *calibrated* upstream — it's still stored and still needs
+            // to be subtracted here.
+            let zero =
+                packed_zero_points[group * cols + c] as f32 + zero_point_offset(request.scheme);
+            let dequantized = (raw - zero) * scale;
+
+            values.push(dequantized);
+        }
+    }
+
+    Ok(Some(DequantPreview { rows, cols, values }))
+}
+
+/// Read and unpack a `rows` by `cols` corner of a packed-int tensor.
+///
+/// `stride` is the tensor's packed column count, i.e. how many packed
+/// 32-bit words a row actually spans in storage (which may be narrower
+/// than `cols` once unpacked, for [`Packing::Columns`]).
+#[allow(clippy::too_many_arguments)]
+async fn read_packed_window(
+    source: &dyn MetadataSource,
+    tensor: &TensorWindowRef,
+    packing: Packing,
+    rows: usize,
+    cols: usize,
+    n_bits: usize,
+    n_packed: usize,
+    stride: usize,
+    scheme: DequantScheme,
+) -> Result<Vec<i64>> {
+    let header_end = 8 + tensor.header_size;
+    let data_start = tensor.data_offsets[0] as u64;
+
+    let mut values = vec![0i64; rows * cols];
+    match packing {
+        Packing::Columns => {
+            let packed_cols_needed = (cols - 1) / n_packed + 1;
+            for r in 0..rows {
+                let row_start = data_start + (r * stride) as u64 * 4;
+                let range = (header_end + row_start)
+                    ..(header_end + row_start + packed_cols_needed as u64 * 4);
+                let bytes = source.read_tensor_bytes(&tensor.checkpoint, range).await?;
+                for (pc, word) in bytes.chunks_exact(4).enumerate() {
+                    let word = u32::from_le_bytes(word.try_into().unwrap());
+                    for lane in 0..n_packed {
+                        let c = pc * n_packed + lane;
+                        if c >= cols {
+                            break;
+                        }
+                        values[r * cols + c] = unpack_lane(word, lane, n_bits, n_packed, scheme);
+                    }
+                }
+            }
+        }
+        Packing::Rows => {
+            let packed_rows_needed = (rows - 1) / n_packed + 1;
+            let row_bytes = stride as u64 * 4;
+            let range = (header_end + data_start)
+                ..(header_end + data_start + packed_rows_needed as u64 * row_bytes);
+            let bytes = source.read_tensor_bytes(&tensor.checkpoint, range).await?;
+            for (pr, packed_row) in bytes.chunks_exact(row_bytes as usize).enumerate() {
+                for (word_idx, word) in packed_row.chunks_exact(4).take(cols).enumerate() {
+                    let word = u32::from_le_bytes(word.try_into().unwrap());
+                    for lane in 0..n_packed {
+                        let r = pr * n_packed + lane;
+                        if r >= rows {
+                            break;
+                        }
+                        values[r * cols + word_idx] = unpack_lane(word, lane, n_bits, n_packed, scheme);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+/// Extract the `lane`-th `n_bits`-wide value packed into `word`.
+///
+/// AWQ's GEMM kernel shuffles the lane order for 4-bit packing
+/// ([`AWQ_NIBBLE_ORDER`]); every other packing this module handles
+/// (GPTQ, and AWQ at other bit widths) is sequential. The shuffle is keyed
+/// on `scheme`, not just bit width: GPTQ also packs four-bit values eight
+/// to a word, but sequentially, so checking `n_bits`/`n_packed` alone would
+/// wrongly shuffle GPTQ's layout too.
+fn unpack_lane(word: u32, lane: usize, n_bits: usize, n_packed: usize, scheme: DequantScheme) -> i64 {
+    let mask = (1u32 << n_bits) - 1;
+    let shift = if scheme == DequantScheme::Awq && n_bits == 4 && n_packed == 8 {
+        AWQ_NIBBLE_ORDER[lane] * n_bits
+    } else {
+        lane * n_bits
+    };
+    ((word >> shift) & mask) as i64
+}
+
+/// GPTQ's packed zero-points are historically stored off-by-one (a
+/// GPTQ-for-LLaMa quirk every downstream kernel still honours); AWQ stores
+/// them directly.
+fn zero_point_offset(scheme: DequantScheme) -> f32 {
+    match scheme {
+        DequantScheme::Gptq => 1.0,
+        DequantScheme::Awq => 0.0,
+    }
+}
+
+/// Read a `desc_act` checkpoint's `g_idx` tensor's first `rows` entries,
+/// each row's group index into `scales`/`qzeros`. Like `scales`, `g_idx` is
+/// a plain (unpacked) numeric tensor rather than a quantized one.
+async fn read_group_indices(
+    source: &dyn MetadataSource,
+    tensor: &TensorWindowRef,
+    rows: usize,
+) -> Result<Vec<usize>> {
+    let Some(element_size) = crate::stats::element_size(tensor.dtype) else {
+        return Ok(Vec::new());
+    };
+    let header_end = 8 + tensor.header_size;
+    let data_start = tensor.data_offsets[0] as u64;
+    let range = (header_end + data_start)
+        ..(header_end + data_start + rows as u64 * element_size as u64);
+    let bytes = source.read_tensor_bytes(&tensor.checkpoint, range).await?;
+
+    Ok(decode(tensor.dtype, &bytes)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| v as usize)
+        .collect())
+}
+
+/// Read and decode a `rows` by `cols` corner of a `scales` tensor, a plain
+/// (unpacked) numeric tensor rather than a quantized one.
+async fn read_scale_window(
+    source: &dyn MetadataSource,
+    tensor: &TensorWindowRef,
+    rows: usize,
+    cols: usize,
+) -> Result<Vec<f32>> {
+    let Some(element_size) = crate::stats::element_size(tensor.dtype) else {
+        return Ok(Vec::new());
+    };
+    let stride = tensor.shape.get(1).copied().unwrap_or(cols);
+    let header_end = 8 + tensor.header_size;
+    let data_start = tensor.data_offsets[0] as u64;
+
+    let mut bytes = Vec::with_capacity(rows * cols * element_size);
+    for r in 0..rows {
+        let row_start = data_start + (r * stride) as u64 * element_size as u64;
+        let range =
+            (header_end + row_start)..(header_end + row_start + cols as u64 * element_size as u64);
+        bytes.extend(source.read_tensor_bytes(&tensor.checkpoint, range).await?);
+    }
+
+    Ok(decode(tensor.dtype, &bytes)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| v as f32)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dequant::{unpack_lane, zero_point_offset, DequantScheme, AWQ_NIBBLE_ORDER};
+
+    /// GPTQ packs four-bit values sequentially: lane `i` sits at bit offset
+    /// `i * 4`. Build a word whose nibble at sequential position `p` holds
+    /// the value `p`, so a correct sequential unpack returns `lane` back.
+    #[test]
+    fn unpack_lane_gptq_four_bit_is_sequential() {
+        let word: u32 = (0..8).map(|p| p << (p * 4)).sum();
+        for lane in 0..8 {
+            assert_eq!(
+                unpack_lane(word, lane, 4, 8, DequantScheme::Gptq),
+                lane as i64
+            );
+        }
+    }
+
+    /// AWQ's GEMM kernel shuffles which nibble holds lane `i`
+    /// ([`AWQ_NIBBLE_ORDER`]); the same word as above should come back
+    /// permuted rather than sequential.
+    #[test]
+    fn unpack_lane_awq_four_bit_uses_shuffled_order() {
+        let word: u32 = (0..8).map(|p| p << (p * 4)).sum();
+        for lane in 0..8 {
+            assert_eq!(
+                unpack_lane(word, lane, 4, 8, DequantScheme::Awq),
+                AWQ_NIBBLE_ORDER[lane] as i64
+            );
+        }
+    }
+
+    /// GPTQ at other bit widths (e.g. 8-bit, four values to a word) isn't
+    /// nibble-shuffled at all, only its four-bit packing is.
+    #[test]
+    fn unpack_lane_eight_bit_is_always_sequential() {
+        let word: u32 = (0..4).map(|p| p << (p * 8)).sum();
+        for lane in 0..4 {
+            assert_eq!(
+                unpack_lane(word, lane, 8, 4, DequantScheme::Gptq),
+                lane as i64
+            );
+            assert_eq!(
+                unpack_lane(word, lane, 8, 4, DequantScheme::Awq),
+                lane as i64
+            );
+        }
+    }
+
+    #[test]
+    fn zero_point_offset_differs_by_scheme() {
+        assert_eq!(zero_point_offset(DequantScheme::Gptq), 1.0);
+        assert_eq!(zero_point_offset(DequantScheme::Awq), 0.0);
+    }
+}
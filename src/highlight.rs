@@ -0,0 +1,36 @@
+use ansi_to_tui::IntoText;
+use color_eyre::eyre::Result;
+use ratatui::text::Text;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
+
+/// Syntax-highlight `json` for terminal display: tokenize it with
+/// `syntect` and turn the resulting ANSI-escaped text into ratatui
+/// [`Text`] spans via `ansi-to-tui`.
+///
+/// Falls back to plain, unstyled text if the ANSI output can't be parsed
+/// back into spans, rather than failing the whole raw view over a
+/// cosmetic issue.
+pub fn highlight_json(json: &str) -> Result<Text<'static>> {
+    let syntax_set = SyntaxSet::load_defaults_newlines();
+    let theme_set = ThemeSet::load_defaults();
+    let syntax = syntax_set
+        .find_syntax_by_extension("json")
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let theme = &theme_set.themes["base16-ocean.dark"];
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    let mut lines = Vec::new();
+    for line in LinesWithEndings::from(json) {
+        let ranges = highlighter.highlight_line(line, &syntax_set)?;
+        let escaped = as_24_bit_terminal_escaped(&ranges[..], false);
+        match escaped.into_text() {
+            Ok(text) => lines.extend(text.lines),
+            Err(_) => lines.push(line.trim_end_matches('\n').to_owned().into()),
+        }
+    }
+
+    Ok(Text::from(lines))
+}
@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::{bail, Context, Result};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use safetensors::tensor::Metadata;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::Config;
+use crate::repo::{CheckpointMetadata, MetadataEvent};
+use crate::source::{Index, MetadataSource};
+
+/// Successive write events for the same file within this window are
+/// treated as one reload, so a multi-write save doesn't re-parse a
+/// partially-written header.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// A checkpoint read straight off disk: either a single `.safetensors`
+/// file, or a directory of shards alongside `model.safetensors.index.json`
+/// and `config.json`.
+///
+/// Headers are read directly with a plain file read rather than the
+/// RANGE-request dance in [`crate::repo::SafeTensorsRepo`], mirroring how
+/// that type reads an already-cached shard in `file_from_cache`.
+pub struct LocalRepo {
+    root: PathBuf,
+}
+
+impl LocalRepo {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn read_header(path: &Path) -> Result<(Metadata, u64)> {
+        let f = File::open(path).context(format!("Cannot open {}", path.display()))?;
+        let mut reader = BufReader::new(f);
+
+        let mut header_size_bytes = [0; 8];
+        reader.read_exact(&mut header_size_bytes)?;
+        let header_size = u64::from_le_bytes(header_size_bytes);
+
+        let mut header_bytes = vec![0; header_size as usize];
+        reader.read_exact(&mut header_bytes)?;
+
+        Ok((serde_json::from_slice(&header_bytes)?, header_size))
+    }
+
+    fn shard_path(&self, filename: &str) -> PathBuf {
+        if self.root.is_file() {
+            self.root.clone()
+        } else {
+            self.root.join(filename)
+        }
+    }
+
+    /// Watch this checkpoint's file (or directory) for writes, re-reading a
+    /// shard's header and sending a fresh [`MetadataEvent::Checkpoint`]
+    /// whenever it changes.
+    ///
+    /// The returned watcher must be kept alive for as long as reload
+    /// events are wanted; dropping it stops the watch. A shard that fails
+    /// to parse (e.g. caught mid-write) is silently skipped rather than
+    /// reported as an error, leaving the previously loaded metadata in
+    /// place until a later write succeeds.
+    pub fn watch(&self, events: UnboundedSender<Result<MetadataEvent>>) -> Result<RecommendedWatcher> {
+        let root = self.root.clone();
+        let is_file = self.root.is_file();
+        let mut last_seen: HashMap<PathBuf, Instant> = HashMap::new();
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            let Ok(event) = res else { return };
+            if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                return;
+            }
+
+            for path in event.paths {
+                if path.extension().and_then(|ext| ext.to_str()) != Some("safetensors") {
+                    continue;
+                }
+
+                let now = Instant::now();
+                if let Some(last) = last_seen.get(&path) {
+                    if now.duration_since(*last) < DEBOUNCE {
+                        continue;
+                    }
+                }
+                last_seen.insert(path.clone(), now);
+
+                let filename = if is_file {
+                    root.file_name().map(|name| name.to_string_lossy().into_owned())
+                } else {
+                    path.file_name().map(|name| name.to_string_lossy().into_owned())
+                };
+
+                let Some(filename) = filename else { continue };
+
+                // A failed parse most likely means we caught the file
+                // mid-write; keep the previously loaded metadata and wait
+                // for the next write instead of surfacing an error.
+                if let Ok((metadata, header_size)) = Self::read_header(&path) {
+                    let _ = events.send(Ok(MetadataEvent::Checkpoint(CheckpointMetadata {
+                        filename,
+                        metadata,
+                        header_size,
+                    })));
+                }
+            }
+        })?;
+
+        watcher.watch(&self.root, RecursiveMode::NonRecursive)?;
+
+        Ok(watcher)
+    }
+}
+
+#[async_trait::async_trait]
+impl MetadataSource for LocalRepo {
+    async fn get_checkpoint_metadatas(
+        &self,
+        events: UnboundedSender<Result<MetadataEvent>>,
+    ) -> Result<()> {
+        let filenames = self.get_safetensors_index().await?;
+        let total = filenames.len();
+
+        for (done, filename) in filenames.into_iter().enumerate() {
+            let result = Self::read_header(&self.shard_path(&filename)).map(
+                |(metadata, header_size)| CheckpointMetadata {
+                    filename,
+                    metadata,
+                    header_size,
+                },
+            );
+
+            match result {
+                Ok(metadata) => {
+                    let _ = events.send(Ok(MetadataEvent::Progress {
+                        done: done + 1,
+                        total,
+                    }));
+                    let _ = events.send(Ok(MetadataEvent::Checkpoint(metadata)));
+                }
+                Err(err) => {
+                    let _ = events.send(Err(err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_config(&self) -> Result<Config> {
+        let config_path = if self.root.is_file() {
+            self.root.with_file_name("config.json")
+        } else {
+            self.root.join("config.json")
+        };
+
+        if !config_path.is_file() {
+            return Ok(Config::default());
+        }
+
+        let reader = BufReader::new(File::open(&config_path).context(format!(
+            "Cannot open model configuration for reading: {}",
+            config_path.display()
+        ))?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    async fn get_raw_config(&self) -> Result<Option<String>> {
+        let config_path = if self.root.is_file() {
+            self.root.with_file_name("config.json")
+        } else {
+            self.root.join("config.json")
+        };
+
+        if !config_path.is_file() {
+            return Ok(None);
+        }
+
+        Ok(Some(std::fs::read_to_string(&config_path).context(
+            format!(
+                "Cannot open model configuration for reading: {}",
+                config_path.display()
+            ),
+        )?))
+    }
+
+    async fn get_safetensors_index(&self) -> Result<Vec<String>> {
+        if self.root.is_file() {
+            let filename = self
+                .root
+                .file_name()
+                .context(format!("{} has no file name", self.root.display()))?
+                .to_string_lossy()
+                .into_owned();
+            return Ok(vec![filename]);
+        }
+
+        let index_path = self.root.join("model.safetensors.index.json");
+        if index_path.is_file() {
+            let reader = BufReader::new(File::open(&index_path)?);
+            let index: Index = serde_json::from_reader(reader)?;
+            let checkpoint_set = index.weight_map.into_values().collect::<HashSet<_>>();
+            return Ok(checkpoint_set.into_iter().collect());
+        }
+
+        if self.root.join("model.safetensors").is_file() {
+            return Ok(vec!["model.safetensors".to_string()]);
+        }
+
+        bail!(
+            "{} is neither a .safetensors file nor a directory containing shards",
+            self.root.display()
+        )
+    }
+
+    async fn read_tensor_bytes(&self, checkpoint: &str, range: Range<u64>) -> Result<Vec<u8>> {
+        let path = self.shard_path(checkpoint);
+        let mut f = File::open(&path).context(format!("Cannot open {}", path.display()))?;
+        f.seek(SeekFrom::Start(range.start))?;
+
+        let mut buf = vec![0; (range.end - range.start) as usize];
+        f.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+}
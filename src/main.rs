@@ -1,7 +1,10 @@
+use std::path::Path;
+use std::sync::Arc;
+
 use clap::Parser;
 use color_eyre::eyre::Result;
 use hf_hub::{api::tokio::Api, Cache, Repo, RepoType};
-use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
 
 pub mod app;
 pub use app::App;
@@ -9,39 +12,129 @@ pub use app::App;
 mod input;
 pub use input::InputState;
 
+mod local;
+use local::LocalRepo;
+
 pub mod metadata;
-use metadata::get_tensors;
+
+mod config;
+
+mod dequant;
+
+mod highlight;
+
+mod models;
 
 mod repo;
 use repo::SafeTensorsRepo;
 
+mod sort;
+
+mod source;
+use source::MetadataSource;
+
+mod stats;
+
+mod summary;
+
 pub(crate) mod utils;
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 struct Cli {
+    /// A Hub repo id (e.g. `meta-llama/Llama-3.1-8B`), or a path to a local
+    /// `.safetensors` file or a directory containing shards.
     repo: String,
 
     #[arg(short, long)]
     revision: Option<String>,
-}
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
+    /// Dump resolved tensor metadata as JSON to stdout and exit, instead
+    /// of opening the TUI.
+    #[arg(long)]
+    json: bool,
+}
 
-    let cli = Cli::parse();
+/// The local watcher is only returned when `cli.repo` resolved to a
+/// [`LocalRepo`]; the caller must hold onto it for as long as live reload
+/// is wanted, since dropping it stops the watch.
+fn source(
+    cli: Cli,
+    events: mpsc::UnboundedSender<Result<repo::MetadataEvent>>,
+) -> Result<(Box<dyn MetadataSource>, Option<notify::RecommendedWatcher>)> {
+    if Path::new(&cli.repo).exists() {
+        let local_repo = LocalRepo::new(cli.repo);
+        let watcher = local_repo.watch(events)?;
+        return Ok((Box::new(local_repo), Some(watcher)));
+    }
 
     let api = Api::new()?;
     let revision = cli.revision.unwrap_or_else(|| "main".to_string());
     let repo = Repo::with_revision(cli.repo, RepoType::Model, revision);
     let xdg_dir = xdg::BaseDirectories::with_prefix("safetensors-browser")?;
     let cache_repo = Cache::new(xdg_dir.get_cache_home()).repo(repo.clone());
-    let safetensors_repo = SafeTensorsRepo::new(&api, repo, cache_repo);
-    let rt = Runtime::new()?;
-    let checkpoint_metadata = rt.block_on(safetensors_repo.get_checkpoint_metadatas())?;
+    Ok((Box::new(SafeTensorsRepo::new(&api, repo, cache_repo)), None))
+}
+
+/// Resolve every shard synchronously and print the resulting tensor
+/// metadata as JSON, for the `--json` flag.
+///
+/// `events` is drained with `try_recv` rather than `recv` once resolution
+/// has finished: a [`LocalRepo`] keeps its filesystem watcher's sender
+/// clone alive for the lifetime of the process, so the channel never
+/// closes on its own and waiting for that would hang.
+async fn dump_json(
+    source: &dyn MetadataSource,
+    config: config::Config,
+    events: mpsc::UnboundedSender<Result<repo::MetadataEvent>>,
+    rx: &mut mpsc::UnboundedReceiver<Result<repo::MetadataEvent>>,
+) -> Result<()> {
+    source.get_checkpoint_metadatas(events).await?;
+
+    let mut checkpoint_metadatas = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        if let repo::MetadataEvent::Checkpoint(checkpoint_metadata) = event? {
+            checkpoint_metadatas.push(checkpoint_metadata);
+        }
+    }
+
+    let tensors = metadata::get_tensors(&config, &checkpoint_metadatas)?;
+    let export = metadata::export_tensors(&tensors);
+    println!("{}", serde_json::to_string_pretty(&export)?);
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse();
+    let json = cli.json;
+
+    // Shard headers stream in over this channel as they resolve, so the
+    // TUI can open immediately and populate the tensor list incrementally
+    // instead of blocking on the whole checkpoint up front. Reloads
+    // triggered by the filesystem watcher reuse the same channel.
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let (source, _watcher) = source(cli, tx.clone())?;
+    let source: Arc<dyn MetadataSource> = Arc::from(source);
+
+    let config = source.get_config().await?;
+
+    if json {
+        return dump_json(source.as_ref(), config, tx, &mut rx).await;
+    }
+
+    let checkpoint_source = Arc::clone(&source);
+    tokio::spawn(async move {
+        if let Err(err) = checkpoint_source.get_checkpoint_metadatas(tx.clone()).await {
+            let _ = tx.send(Err(err));
+        }
+    });
 
     let terminal = ratatui::init();
-    let result = App::new(get_tensors(&checkpoint_metadata)?).run(terminal);
+    let result = App::new(config, source).run(terminal, rx).await;
     ratatui::restore();
 
     result
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 use std::num::NonZeroUsize;
 use std::str;
@@ -9,8 +9,9 @@ use num_bigint::BigInt;
 use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
 use safetensors::tensor::TensorInfo;
+use serde::Serialize;
 
-use crate::config::{AwqVersion, Config, QuantizationConfig};
+use crate::config::{AwqVersion, Bnb4BitQuantType, Config, QuantizationConfig};
 use crate::repo::CheckpointMetadata;
 
 #[derive(Debug)]
@@ -18,6 +19,10 @@ pub struct TensorMetadata {
     pub name: String,
     pub tensor_info: TensorInfo,
     pub checkpoint: String,
+    /// Size in bytes of the checkpoint's JSON header, needed to translate
+    /// `tensor_info.data_offsets` (relative to the data section) into an
+    /// absolute byte range within the checkpoint file.
+    pub header_size: u64,
     pub quantization: Option<Quantization>,
 }
 
@@ -69,8 +74,9 @@ impl Quantization {
         match self.qtype {
             QuantizationType::Awq {
                 version: AwqVersion::Gemm,
-            } => {
-                let n_packed = 32 / self.dtype.n_bits();
+            }
+            | QuantizationType::Bnb4Bit => {
+                let n_packed = self.dtype.n_packed()?;
                 let mut dequantized_shape = quantized_shape.to_owned();
                 let last = dequantized_shape.last_mut()?;
                 *last *= n_packed;
@@ -80,7 +86,7 @@ impl Quantization {
                 })
             }
             QuantizationType::Gptq { .. } => {
-                let n_packed = 32 / self.dtype.n_bits();
+                let n_packed = self.dtype.n_packed()?;
                 let mut dequantized_shape = quantized_shape.to_owned();
                 let first = dequantized_shape.first_mut()?;
                 *first *= n_packed;
@@ -89,7 +95,13 @@ impl Quantization {
                     layout: LinearLayout::InFeaturesOutFeatures,
                 })
             }
-            _ => None,
+            // FP8 weights aren't repacked: one quantized value occupies
+            // one stored byte, so the quantized shape already is the
+            // dequantized shape.
+            QuantizationType::Fp8
+            | QuantizationType::Awq {
+                version: AwqVersion::Gemmv | AwqVersion::GemmvFast,
+            } => None,
         }
     }
 
@@ -98,8 +110,9 @@ impl Quantization {
             QuantizationType::Awq {
                 version: AwqVersion::Gemm,
             }
-            | QuantizationType::Gptq { .. } => {
-                let n_packed = 32 / self.dtype.n_bits();
+            | QuantizationType::Gptq { .. }
+            | QuantizationType::Bnb4Bit => {
+                let n_packed = self.dtype.n_packed()?;
                 let mut dequantized_shape = quantized_shape.to_owned();
                 let last = dequantized_shape.last_mut()?;
                 *last *= n_packed;
@@ -108,7 +121,10 @@ impl Quantization {
                     layout: LinearLayout::OutFeaturesInFeatures,
                 })
             }
-            _ => None,
+            QuantizationType::Fp8
+            | QuantizationType::Awq {
+                version: AwqVersion::Gemmv | AwqVersion::GemmvFast,
+            } => None,
         }
     }
 }
@@ -117,6 +133,8 @@ impl Quantization {
 pub enum QuantizationType {
     Awq { version: AwqVersion },
     Gptq { desc_act: bool, static_groups: bool },
+    Fp8,
+    Bnb4Bit,
 }
 
 impl Display for QuantizationType {
@@ -124,28 +142,133 @@ impl Display for QuantizationType {
         match self {
             QuantizationType::Awq { .. } => f.write_str("AWQ"),
             QuantizationType::Gptq { .. } => f.write_str("GPTQ"),
+            QuantizationType::Fp8 => f.write_str("FP8"),
+            QuantizationType::Bnb4Bit => f.write_str("bitsandbytes"),
         }
     }
 }
 
+/// Which role a quantized tensor plays, as determined by
+/// [`QuantizationType::tensor_kind`] from its name. Shared by every place
+/// that needs to tell a checkpoint's quantized weight apart from its
+/// zero-points/scales, rather than each re-deriving it from a name
+/// whitelist.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QuantizedTensorKind {
+    Weight,
+    ZeroPoint,
+    Scale,
+}
+
+impl QuantizationType {
+    /// Which role (if any) a tensor named `name` plays for this
+    /// quantization scheme. AWQ/GPTQ split the weight across a
+    /// `qweight`/`qzeros`/`scales` triple; FP8/bitsandbytes store
+    /// everything under the original `weight` name.
+    fn tensor_kind(&self, name: &str) -> Option<QuantizedTensorKind> {
+        match self {
+            QuantizationType::Awq { .. } | QuantizationType::Gptq { .. } => match name {
+                "qweight" => Some(QuantizedTensorKind::Weight),
+                "qzeros" => Some(QuantizedTensorKind::ZeroPoint),
+                "scales" => Some(QuantizedTensorKind::Scale),
+                _ => None,
+            },
+            QuantizationType::Fp8 | QuantizationType::Bnb4Bit => match name {
+                "weight" => Some(QuantizedTensorKind::Weight),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Fp8Format {
+    E4m3,
+    E5m2,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Float4Format {
+    Nf4,
+    Fp4,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum QuantizedDType {
     Int(NonZeroUsize),
+    Fp8(Fp8Format),
+    Float4(Float4Format),
 }
 
 impl QuantizedDType {
-    fn n_bits(&self) -> NonZeroUsize {
+    pub(crate) fn n_bits(&self) -> NonZeroUsize {
         match self {
             QuantizedDType::Int(bits) => *bits,
+            QuantizedDType::Fp8(_) => NonZeroUsize::new(8).unwrap(),
+            QuantizedDType::Float4(_) => NonZeroUsize::new(4).unwrap(),
+        }
+    }
+
+    /// Bit width of the integer word this dtype's values are packed
+    /// into before storage, or `None` if values aren't packed at all
+    /// (one value per stored byte, as in FP8).
+    fn pack_word_bits(&self) -> Option<usize> {
+        match self {
+            // AWQ/GPTQ pack `n_bits`-wide integers into 32-bit words.
+            QuantizedDType::Int(_) => Some(32),
+            // bitsandbytes packs two 4-bit floats into a single byte.
+            QuantizedDType::Float4(_) => Some(8),
+            QuantizedDType::Fp8(_) => None,
         }
     }
+
+    /// How many quantized values are packed into a single element of the
+    /// underlying storage dtype, or `None` if this dtype isn't repacked
+    /// (so a quantized shape needs no adjustment to be dequantized).
+    pub(crate) fn n_packed(&self) -> Option<usize> {
+        Some(self.pack_word_bits()? / self.n_bits().get())
+    }
 }
 
 impl Display for QuantizedDType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             QuantizedDType::Int(bits) => write!(f, "I{}", bits),
+            QuantizedDType::Fp8(Fp8Format::E4m3) => f.write_str("FP8 (E4M3)"),
+            QuantizedDType::Fp8(Fp8Format::E5m2) => f.write_str("FP8 (E5M2)"),
+            QuantizedDType::Float4(Float4Format::Nf4) => f.write_str("NF4"),
+            QuantizedDType::Float4(Float4Format::Fp4) => f.write_str("FP4"),
+        }
+    }
+}
+
+impl Quantization {
+    pub(crate) fn qtype(&self) -> &QuantizationType {
+        &self.qtype
+    }
+
+    pub(crate) fn dtype(&self) -> QuantizedDType {
+        self.dtype
+    }
+
+    pub(crate) fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// The true (unpacked) element count of the weight tensor named `name`
+    /// (as identified by [`QuantizationType::tensor_kind`]) shaped
+    /// `quantized_shape`, or `None` if `name` isn't this scheme's weight
+    /// tensor, or if the scheme isn't repacked. Used for parameter counting,
+    /// where the stored shape undercounts by a factor of
+    /// [`QuantizedDType::n_packed`].
+    pub(crate) fn dequantized_param_count(&self, name: &str, quantized_shape: &[usize]) -> Option<u64> {
+        let name = name.rsplit_once('.').map(|parts| parts.1).unwrap_or(name);
+        if self.qtype.tensor_kind(name) != Some(QuantizedTensorKind::Weight) {
+            return None;
         }
+
+        self.dequantized_weight_shape(quantized_shape)
+            .map(|shape| shape.shape.iter().product::<usize>() as u64)
     }
 }
 
@@ -180,38 +303,155 @@ impl Quantization {
                 },
                 zero_point: !sym,
             }),
+            // FP8 checkpoints quantize per tensor or per block rather than
+            // by a group size along the input dimension; there's no group
+            // size to surface here. E4M3 is the variant every FP8 weight
+            // quantization scheme in the wild uses for weights.
+            QuantizationConfig::Fp8 { .. } => Some(Quantization {
+                dtype: QuantizedDType::Fp8(Fp8Format::E4m3),
+                group_size: 0,
+                qtype: QuantizationType::Fp8,
+                zero_point: false,
+            }),
+            QuantizationConfig::Bitsandbytes { quant_type, .. } => Some(Quantization {
+                dtype: QuantizedDType::Float4(match quant_type {
+                    Bnb4BitQuantType::Nf4 => Float4Format::Nf4,
+                    Bnb4BitQuantType::Fp4 => Float4Format::Fp4,
+                }),
+                group_size: 0,
+                qtype: QuantizationType::Bnb4Bit,
+                zero_point: false,
+            }),
         }
     }
 }
 
+/// Merge the tensors of a single shard into `tensors`.
+///
+/// Split out from [`get_tensors`] so that callers which resolve shards
+/// incrementally (e.g. as they stream in over the network) can merge each
+/// one in as it arrives instead of waiting for the full checkpoint.
+pub fn insert_tensors(
+    tensors: &mut HashMap<String, TensorMetadata>,
+    config: &Config,
+    checkpoint_metadata: &CheckpointMetadata,
+) {
+    tensors.extend(
+        checkpoint_metadata
+            .metadata
+            .tensors()
+            .into_iter()
+            .map(|(name, tensor_info)| {
+                (
+                    name.clone(),
+                    TensorMetadata {
+                        checkpoint: checkpoint_metadata.filename.clone(),
+                        header_size: checkpoint_metadata.header_size,
+                        name,
+                        quantization: Quantization::new(config),
+                        tensor_info: tensor_info.clone(),
+                    },
+                )
+            }),
+    );
+}
+
 pub fn get_tensors(
     config: &Config,
     checkpoint_metadatas: &[CheckpointMetadata],
 ) -> Result<HashMap<String, TensorMetadata>> {
     let mut tensors = HashMap::new();
     for metadata in checkpoint_metadatas {
-        tensors.extend(
-            metadata
-                .metadata
-                .tensors()
-                .into_iter()
-                .map(|(name, tensor_info)| {
-                    (
-                        name.clone(),
-                        TensorMetadata {
-                            checkpoint: metadata.filename.clone(),
-                            name,
-                            quantization: Quantization::new(config),
-                            tensor_info: tensor_info.clone(),
-                        },
-                    )
-                }),
-        );
+        insert_tensors(&mut tensors, config, metadata);
     }
 
     Ok(tensors)
 }
 
+/// A machine-readable view of a [`TensorMetadata`], for the `--json` CLI
+/// flag rather than the interactive TUI. Mirrors [`RenderMetadata`]'s
+/// fields but as plain, `serde`-serializable data instead of styled
+/// `Line`s.
+#[derive(Serialize)]
+pub struct TensorMetadataExport {
+    pub name: String,
+    pub checkpoint: String,
+    pub dtype: String,
+    pub shape: Vec<usize>,
+    pub data_offsets: [usize; 2],
+    pub quantization: Option<QuantizationExport>,
+}
+
+#[derive(Serialize)]
+pub struct QuantizationExport {
+    pub quantizer: String,
+    pub dtype: String,
+    pub group_size: usize,
+    pub zero_point: bool,
+    pub dequantized_shape: Option<Vec<usize>>,
+    pub dequantized_layout: Option<String>,
+}
+
+impl TensorMetadata {
+    pub fn to_export(&self) -> TensorMetadataExport {
+        TensorMetadataExport {
+            name: self.name.clone(),
+            checkpoint: self.checkpoint.clone(),
+            dtype: format!("{:?}", self.tensor_info.dtype),
+            shape: self.tensor_info.shape.clone(),
+            data_offsets: self.tensor_info.data_offsets,
+            quantization: self.quantization.as_ref().and_then(|q| q.to_export(self)),
+        }
+    }
+}
+
+impl Quantization {
+    /// `None` if `tensor_metadata` isn't actually one of this quantization's
+    /// own tensors (e.g. an embedding, layernorm, or bias sitting alongside a
+    /// quantized weight in the same checkpoint) — those shouldn't report a
+    /// `quantization` block at all.
+    fn to_export(&self, tensor_metadata: &TensorMetadata) -> Option<QuantizationExport> {
+        let name = tensor_metadata
+            .name
+            .rsplit_once('.')
+            .map(|parts| parts.1)
+            .unwrap_or(&tensor_metadata.name);
+
+        let kind = self.qtype.tensor_kind(name)?;
+
+        let dequantized_shape = match kind {
+            QuantizedTensorKind::Weight => {
+                self.dequantized_weight_shape(&tensor_metadata.tensor_info.shape)
+            }
+            QuantizedTensorKind::ZeroPoint => {
+                self.dequantized_zero_point_shape(&tensor_metadata.tensor_info.shape)
+            }
+            QuantizedTensorKind::Scale => None,
+        };
+
+        Some(QuantizationExport {
+            quantizer: self.qtype.to_string(),
+            dtype: self.dtype.to_string(),
+            group_size: self.group_size,
+            zero_point: self.zero_point,
+            dequantized_shape: dequantized_shape.as_ref().map(|s| s.shape.clone()),
+            dequantized_layout: dequantized_shape.map(|s| format!("{:?}", s.layout)),
+        })
+    }
+}
+
+/// Export every tensor in `tensors` as a [`TensorMetadataExport`], keyed by
+/// name in a [`BTreeMap`] rather than a `HashMap` so repeated dumps of the
+/// same checkpoint serialize in the same order and are diffable.
+pub fn export_tensors(
+    tensors: &HashMap<String, TensorMetadata>,
+) -> BTreeMap<String, TensorMetadataExport> {
+    tensors
+        .iter()
+        .map(|(name, metadata)| (name.clone(), metadata.to_export()))
+        .collect()
+}
+
 pub trait RenderMetadata {
     fn render_metadata(&self, tensor_metadata: &TensorMetadata, lines: &mut Vec<Line>);
 }
@@ -256,9 +496,9 @@ impl RenderMetadata for Quantization {
             .map(|parts| parts.1)
             .unwrap_or(&tensor_metadata.name);
 
-        if !(name == "qweight" || name == "qzeros" || name == "scales") {
+        let Some(kind) = self.qtype.tensor_kind(name) else {
             return;
-        }
+        };
 
         let field_style = Style::new().magenta();
         lines.extend([
@@ -286,11 +526,14 @@ impl RenderMetadata for Quantization {
             ]),
         ]);
 
-        let dequantized_shape = match name {
-            "qweight" => self.dequantized_weight_shape(&tensor_metadata.tensor_info.shape),
-            "qzeros" => self.dequantized_zero_point_shape(&tensor_metadata.tensor_info.shape),
-            "scales" => None,
-            _ => unreachable!(),
+        let dequantized_shape = match kind {
+            QuantizedTensorKind::Weight => {
+                self.dequantized_weight_shape(&tensor_metadata.tensor_info.shape)
+            }
+            QuantizedTensorKind::ZeroPoint => {
+                self.dequantized_zero_point_shape(&tensor_metadata.tensor_info.shape)
+            }
+            QuantizedTensorKind::Scale => None,
         };
 
         if let Some(dequantized_shape) = dequantized_shape {
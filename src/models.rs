@@ -15,6 +15,26 @@ pub trait ParamToLayer: Debug {
     }
 }
 
+/// The attention/MLP projection and norm names shared by the Llama family
+/// of architectures (Llama, Mistral, Qwen2, Gemma, Phi all name their
+/// transformer block parameters the same way).
+fn llama_family_params() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("q_proj", "Linear"),
+        ("k_proj", "Linear"),
+        ("v_proj", "Linear"),
+        ("o_proj", "Linear"),
+        ("gate_proj", "Linear"),
+        ("down_proj", "Linear"),
+        ("up_proj", "Linear"),
+        ("input_layernorm", "LayerNorm"),
+        ("post_attention_layernorm", "LayerNorm"),
+        ("norm", "LayerNorm"),
+        ("embed_tokens", "Embedding"),
+        ("lm_head", "Linear"),
+    ])
+}
+
 static LLAMA_PARAMS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
 
 #[derive(Debug)]
@@ -22,16 +42,72 @@ pub struct Llama;
 
 impl ParamToLayer for Llama {
     fn parameter_layer(&self) -> &'static HashMap<&'static str, &'static str> {
-        LLAMA_PARAMS.get_or_init(|| {
-            HashMap::from([
-                ("q_proj", "Linear"),
-                ("k_proj", "Linear"),
-                ("v_proj", "Linear"),
-                ("o_proj", "Linear"),
-                ("gate_proj", "Linear"),
-                ("down_proj", "Linear"),
-                ("up_proj", "Linear"),
-            ])
+        LLAMA_PARAMS.get_or_init(llama_family_params)
+    }
+}
+
+static MISTRAL_PARAMS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+#[derive(Debug)]
+pub struct Mistral;
+
+impl ParamToLayer for Mistral {
+    fn parameter_layer(&self) -> &'static HashMap<&'static str, &'static str> {
+        MISTRAL_PARAMS.get_or_init(llama_family_params)
+    }
+}
+
+static QWEN2_PARAMS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+#[derive(Debug)]
+pub struct Qwen2;
+
+impl ParamToLayer for Qwen2 {
+    fn parameter_layer(&self) -> &'static HashMap<&'static str, &'static str> {
+        QWEN2_PARAMS.get_or_init(llama_family_params)
+    }
+}
+
+static GEMMA_PARAMS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+#[derive(Debug)]
+pub struct Gemma;
+
+impl ParamToLayer for Gemma {
+    fn parameter_layer(&self) -> &'static HashMap<&'static str, &'static str> {
+        GEMMA_PARAMS.get_or_init(llama_family_params)
+    }
+}
+
+static PHI_PARAMS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+#[derive(Debug)]
+pub struct Phi;
+
+impl ParamToLayer for Phi {
+    fn parameter_layer(&self) -> &'static HashMap<&'static str, &'static str> {
+        PHI_PARAMS.get_or_init(llama_family_params)
+    }
+}
+
+static MIXTRAL_PARAMS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+#[derive(Debug)]
+pub struct Mixtral;
+
+impl ParamToLayer for Mixtral {
+    fn parameter_layer(&self) -> &'static HashMap<&'static str, &'static str> {
+        MIXTRAL_PARAMS.get_or_init(|| {
+            let mut params = llama_family_params();
+            // Mixtral replaces the dense MLP with per-expert `w1`/`w2`/`w3`
+            // projections plus a router (`gate`).
+            params.extend([
+                ("w1", "Linear"),
+                ("w2", "Linear"),
+                ("w3", "Linear"),
+                ("gate", "Linear"),
+            ]);
+            params
         })
     }
 }
@@ -39,6 +115,28 @@ impl ParamToLayer for Llama {
 pub fn get_param_layer(model_type: &str) -> Option<Box<dyn ParamToLayer>> {
     match model_type {
         "llama" => Some(Box::new(Llama)),
+        "mistral" => Some(Box::new(Mistral)),
+        "qwen2" => Some(Box::new(Qwen2)),
+        "gemma" | "gemma2" | "gemma3" => Some(Box::new(Gemma)),
+        "phi" | "phi3" => Some(Box::new(Phi)),
+        "mixtral" => Some(Box::new(Mixtral)),
         _ => None,
     }
 }
+
+/// Parse the transformer block index out of a parameter name, e.g. `12`
+/// from `model.layers.12.self_attn.q_proj.weight`.
+///
+/// Recognizes the handful of list names in use across the registered
+/// architectures (`layers` for the Llama family, `experts` for Mixtral's
+/// per-expert tensors); returns `None` for parameters that aren't part of
+/// a repeated block, like embeddings or the final norm.
+pub fn block_index(name: &str) -> Option<usize> {
+    let parts: Vec<&str> = name.split('.').collect();
+    parts.iter().enumerate().find_map(|(i, part)| {
+        if !matches!(*part, "layers" | "experts") {
+            return None;
+        }
+        parts.get(i + 1)?.parse().ok()
+    })
+}
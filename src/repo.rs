@@ -1,31 +1,43 @@
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
+use std::ops::Range;
 
 use bytes::Buf;
 use color_eyre::eyre::{Context, Result};
-use futures::future::try_join_all;
+use futures::stream::{FuturesUnordered, StreamExt};
 use hf_hub::api::tokio::{Api, ApiError, ApiRepo};
 use hf_hub::{CacheRepo, Repo};
-use indicatif::ProgressBar;
 use reqwest::StatusCode;
 use safetensors::tensor::Metadata;
-use serde::Deserialize;
 use tempfile::NamedTempFile;
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::config::Config;
+use crate::source::{Index, MetadataSource};
 use crate::utils::symlink_or_rename;
 
 const MAX_CONCURRENT: usize = 8;
 
-#[derive(Debug, Deserialize)]
-struct Index {
-    pub weight_map: HashMap<String, String>,
-}
-
 pub struct CheckpointMetadata {
     pub filename: String,
     pub metadata: Metadata,
+    /// Size in bytes of the JSON header, i.e. the number of bytes between
+    /// the 8-byte length prefix and the start of the tensor data.
+    pub header_size: u64,
+}
+
+/// An event emitted while checkpoint shards are being resolved.
+///
+/// Checkpoints are not collected into a `Vec` before the caller sees them:
+/// each shard is sent down the channel as soon as its header is available,
+/// so a UI can populate its tensor list incrementally instead of waiting
+/// for every shard to resolve.
+pub enum MetadataEvent {
+    /// A shard's header has been read and is ready to be merged in.
+    Checkpoint(CheckpointMetadata),
+    /// Progress towards resolving all shards, for status display.
+    Progress { done: usize, total: usize },
 }
 
 pub struct SafeTensorsRepo {
@@ -83,6 +95,7 @@ impl SafeTensorsRepo {
         Ok(CheckpointMetadata {
             filename: filename.to_owned(),
             metadata,
+            header_size,
         })
     }
 
@@ -111,54 +124,71 @@ impl SafeTensorsRepo {
             Ok(metadata) => Ok(Some(CheckpointMetadata {
                 metadata,
                 filename: filename.to_owned(),
+                header_size: metadata_length,
             })),
             Err(_) => Ok(None),
         }
     }
 
-    async fn get_file(
-        &self,
-        filename: String,
-        progress: &ProgressBar,
-    ) -> Result<CheckpointMetadata> {
-        let metadata = match self.file_from_cache(&filename)? {
-            Some(metadata) => metadata,
-            None => self.download_file(&filename).await?,
-        };
-        progress.inc(1);
-        Ok(metadata)
+    async fn get_file(&self, filename: String) -> Result<CheckpointMetadata> {
+        match self.file_from_cache(&filename)? {
+            Some(metadata) => Ok(metadata),
+            None => self.download_file(&filename).await,
+        }
     }
+}
 
-    pub async fn get_checkpoint_metadatas(&self) -> Result<Vec<CheckpointMetadata>> {
+#[async_trait::async_trait]
+impl MetadataSource for SafeTensorsRepo {
+    /// Resolve every shard of the checkpoint, sending a [`MetadataEvent`]
+    /// down `events` as each shard's header is read rather than
+    /// collecting them into a `Vec` first.
+    ///
+    /// Up to [`MAX_CONCURRENT`] shards are in flight at once; a finished
+    /// shard is immediately replaced with the next one in the queue, so
+    /// the caller sees results as soon as they're available instead of in
+    /// batches.
+    async fn get_checkpoint_metadatas(
+        &self,
+        events: UnboundedSender<Result<MetadataEvent>>,
+    ) -> Result<()> {
         let checkpoints = self.get_safetensors_index().await?;
-
-        let progress = ProgressBar::new(checkpoints.len() as u64);
-        progress.tick();
+        let total = checkpoints.len();
 
         let info = self.api_repo.info().await?;
         self.cache_repo.create_ref(&info.sha)?;
 
-        let mut results = Vec::new();
-        let mut tasks = Vec::new();
-        for checkpoint in checkpoints {
-            tasks.push(self.get_file(checkpoint, &progress));
+        let mut pending = FuturesUnordered::new();
+        let mut remaining = checkpoints.into_iter();
 
-            if tasks.len() == MAX_CONCURRENT {
-                results.extend(try_join_all(tasks).await?);
-                tasks = Vec::new();
-            }
+        for checkpoint in remaining.by_ref().take(MAX_CONCURRENT) {
+            pending.push(self.get_file(checkpoint));
         }
 
-        if !tasks.is_empty() {
-            results.extend(try_join_all(tasks).await?);
-        }
+        let mut done = 0;
+        while let Some(result) = pending.next().await {
+            if let Some(checkpoint) = remaining.next() {
+                pending.push(self.get_file(checkpoint));
+            }
 
-        progress.finish();
+            match result {
+                Ok(metadata) => {
+                    done += 1;
+                    // Drop the event if the receiver has already gone away
+                    // (e.g. the TUI quit mid-download).
+                    let _ = events.send(Ok(MetadataEvent::Progress { done, total }));
+                    let _ = events.send(Ok(MetadataEvent::Checkpoint(metadata)));
+                }
+                Err(err) => {
+                    let _ = events.send(Err(err));
+                }
+            }
+        }
 
-        Ok(results)
+        Ok(())
     }
 
-    pub async fn get_config(&self) -> Result<Config> {
+    async fn get_config(&self) -> Result<Config> {
         let config_file = self.api_repo.get("config.json").await?;
         let reader = BufReader::new(File::open(&config_file).context(format!(
             "Cannot open model configuration for reading: {}",
@@ -167,6 +197,16 @@ impl SafeTensorsRepo {
         Ok(serde_json::from_reader(reader)?)
     }
 
+    async fn get_raw_config(&self) -> Result<Option<String>> {
+        let config_file = self.api_repo.get("config.json").await?;
+        Ok(Some(std::fs::read_to_string(&config_file).context(
+            format!(
+                "Cannot open model configuration for reading: {}",
+                config_file.to_string_lossy()
+            ),
+        )?))
+    }
+
     async fn get_safetensors_index(&self) -> Result<Vec<String>> {
         let index_file = match self.api_repo.get("model.safetensors.index.json").await {
             Ok(index_file) => Ok(index_file),
@@ -187,4 +227,19 @@ impl SafeTensorsRepo {
         let checkpoint_set = index.weight_map.into_values().collect::<HashSet<_>>();
         Ok(checkpoint_set.into_iter().collect())
     }
+
+    async fn read_tensor_bytes(&self, checkpoint: &str, range: Range<u64>) -> Result<Vec<u8>> {
+        let url = self.api_repo.url(checkpoint);
+        let response = self
+            .api
+            .client()
+            .get(&url)
+            .header(
+                "RANGE",
+                format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+            )
+            .send()
+            .await?;
+        Ok(response.bytes().await?.to_vec())
+    }
 }
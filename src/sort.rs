@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::metadata::{cmp_numeric_lexicographic, TensorMetadata};
+
+/// Which field to order the tensor list by.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortKey {
+    #[default]
+    Name,
+    Size,
+    ElementCount,
+    DType,
+    Checkpoint,
+}
+
+impl SortKey {
+    /// Cycle to the next key, wrapping back to [`SortKey::Name`], for a
+    /// single keybinding that steps through every sort field.
+    pub fn next(self) -> Self {
+        match self {
+            SortKey::Name => SortKey::Size,
+            SortKey::Size => SortKey::ElementCount,
+            SortKey::ElementCount => SortKey::DType,
+            SortKey::DType => SortKey::Checkpoint,
+            SortKey::Checkpoint => SortKey::Name,
+        }
+    }
+}
+
+impl Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SortKey::Name => "name",
+            SortKey::Size => "size",
+            SortKey::ElementCount => "element count",
+            SortKey::DType => "dtype",
+            SortKey::Checkpoint => "file",
+        })
+    }
+}
+
+/// Ascending/descending toggle for [`SortKey`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggle(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+impl Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        })
+    }
+}
+
+/// Compare two tensors by `key`/`direction`, falling back to the
+/// natural-name comparator on ties so the list order is always fully
+/// determined — e.g. two tensors of the same byte size don't jump around
+/// between frames.
+pub fn cmp_tensors(
+    tensors: &HashMap<String, TensorMetadata>,
+    key: SortKey,
+    direction: SortDirection,
+    name1: &str,
+    name2: &str,
+) -> Ordering {
+    let tensor1 = &tensors[name1];
+    let tensor2 = &tensors[name2];
+
+    let ordering = match key {
+        SortKey::Name => cmp_numeric_lexicographic(name1, name2),
+        SortKey::Size => byte_size(tensor1).cmp(&byte_size(tensor2)),
+        SortKey::ElementCount => element_count(tensor1).cmp(&element_count(tensor2)),
+        SortKey::DType => format!("{:?}", tensor1.tensor_info.dtype)
+            .cmp(&format!("{:?}", tensor2.tensor_info.dtype)),
+        SortKey::Checkpoint => tensor1.checkpoint.cmp(&tensor2.checkpoint),
+    };
+
+    let ordering = match direction {
+        SortDirection::Ascending => ordering,
+        SortDirection::Descending => ordering.reverse(),
+    };
+
+    ordering.then_with(|| cmp_numeric_lexicographic(name1, name2))
+}
+
+fn byte_size(tensor: &TensorMetadata) -> usize {
+    tensor.tensor_info.data_offsets[1] - tensor.tensor_info.data_offsets[0]
+}
+
+fn element_count(tensor: &TensorMetadata) -> usize {
+    tensor.tensor_info.shape.iter().product()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use safetensors::tensor::{Dtype, TensorInfo};
+
+    use super::{cmp_tensors, SortDirection, SortKey};
+    use crate::metadata::TensorMetadata;
+
+    fn tensor(
+        name: &str,
+        checkpoint: &str,
+        shape: Vec<usize>,
+        data_offsets: [usize; 2],
+    ) -> TensorMetadata {
+        TensorMetadata {
+            name: name.to_string(),
+            checkpoint: checkpoint.to_string(),
+            header_size: 0,
+            quantization: None,
+            tensor_info: TensorInfo {
+                dtype: Dtype::F32,
+                shape,
+                data_offsets,
+            },
+        }
+    }
+
+    fn tensors(entries: Vec<TensorMetadata>) -> std::collections::HashMap<String, TensorMetadata> {
+        entries.into_iter().map(|t| (t.name.clone(), t)).collect()
+    }
+
+    #[test]
+    fn test_cmp_tensors_by_size() {
+        let tensors = tensors(vec![
+            tensor("a", "ckpt", vec![4], [0, 16]),
+            tensor("b", "ckpt", vec![4], [16, 24]),
+        ]);
+
+        assert_eq!(
+            cmp_tensors(&tensors, SortKey::Size, SortDirection::Ascending, "a", "b"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            cmp_tensors(&tensors, SortKey::Size, SortDirection::Descending, "a", "b"),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_cmp_tensors_by_element_count() {
+        let tensors = tensors(vec![
+            tensor("a", "ckpt", vec![2, 3], [0, 0]),
+            tensor("b", "ckpt", vec![10], [0, 0]),
+        ]);
+
+        assert_eq!(
+            cmp_tensors(
+                &tensors,
+                SortKey::ElementCount,
+                SortDirection::Ascending,
+                "a",
+                "b"
+            ),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_cmp_tensors_falls_back_to_name_on_tie() {
+        let tensors = tensors(vec![
+            tensor("layer2", "ckpt", vec![4], [0, 16]),
+            tensor("layer10", "ckpt", vec![4], [0, 16]),
+        ]);
+
+        assert_eq!(
+            cmp_tensors(
+                &tensors,
+                SortKey::Size,
+                SortDirection::Ascending,
+                "layer2",
+                "layer10"
+            ),
+            Ordering::Less
+        );
+    }
+}
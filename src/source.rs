@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use color_eyre::eyre::Result;
+use serde::Deserialize;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::config::Config;
+use crate::repo::MetadataEvent;
+
+/// The `model.safetensors.index.json` shard manifest, shared by every
+/// [`MetadataSource`] that lays shards out the Hub way.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Index {
+    pub weight_map: HashMap<String, String>,
+}
+
+/// A source the TUI can browse for checkpoint metadata: a Hub repository
+/// ([`crate::repo::SafeTensorsRepo`]) or a checkpoint read directly off
+/// disk ([`crate::local::LocalRepo`]).
+///
+/// Keeping the TUI behind this trait rather than `SafeTensorsRepo` directly
+/// means `App` doesn't care whether shards are arriving over HTTP RANGE
+/// requests or a local filesystem read.
+///
+/// `Send + Sync` so a source can be shared behind an `Arc` and read from
+/// background tasks (e.g. a tensor preview fetch) without blocking the
+/// render loop.
+#[async_trait::async_trait]
+pub trait MetadataSource: Send + Sync {
+    /// Resolve every shard, sending a [`MetadataEvent`] down `events` as
+    /// each one becomes available.
+    async fn get_checkpoint_metadatas(
+        &self,
+        events: UnboundedSender<Result<MetadataEvent>>,
+    ) -> Result<()>;
+
+    /// Load the model's `config.json`.
+    async fn get_config(&self) -> Result<Config>;
+
+    /// Load `config.json` as unparsed text, for the raw metadata view.
+    /// `None` if the checkpoint has no `config.json` at all, rather than an
+    /// error, since that's a valid (if unusual) checkpoint layout.
+    async fn get_raw_config(&self) -> Result<Option<String>>;
+
+    /// List the checkpoint's shard filenames.
+    async fn get_safetensors_index(&self) -> Result<Vec<String>>;
+
+    /// Fetch a byte range of `checkpoint`'s raw file contents, e.g. the
+    /// data span of a single tensor, without reading the whole shard.
+    async fn read_tensor_bytes(&self, checkpoint: &str, range: Range<u64>) -> Result<Vec<u8>>;
+}
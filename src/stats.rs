@@ -0,0 +1,242 @@
+use color_eyre::eyre::Result;
+use safetensors::Dtype;
+
+use crate::source::MetadataSource;
+
+/// Tensors larger than this many bytes are only previewed from their
+/// leading window rather than fetched in full.
+const MAX_PREVIEW_BYTES: u64 = 4 * 1024 * 1024;
+
+const HISTOGRAM_BINS: usize = 32;
+
+/// The handful of fields [`compute_tensor_stats`] needs to fetch and
+/// decode a tensor's values, owned rather than borrowed from
+/// [`crate::metadata::TensorMetadata`] so it can be moved into a
+/// background task independent of the render loop.
+#[derive(Clone, Debug)]
+pub struct TensorRef {
+    pub checkpoint: String,
+    pub header_size: u64,
+    pub data_offsets: [usize; 2],
+    pub dtype: Dtype,
+}
+
+/// Summary statistics and a histogram of a tensor's values, computed from
+/// a RANGE-fetched slice of its raw bytes.
+#[derive(Debug)]
+pub struct TensorStats {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std: f64,
+    pub histogram: Vec<usize>,
+    /// How many decoded values were `NaN`, excluded from `min`/`max`/`mean`/
+    /// `std`/`histogram` so a handful of corrupted values don't poison the
+    /// rest of the summary.
+    pub nan_count: usize,
+    /// How many decoded values were `+Inf`/`-Inf`, likewise excluded from
+    /// the rest of the summary.
+    pub inf_count: usize,
+    /// `true` if only a leading window of the tensor was decoded because
+    /// it was too large to fetch in full.
+    pub sampled: bool,
+}
+
+/// Fetch and decode the selected tensor's values, if its dtype is a plain
+/// numeric type we know how to decode.
+///
+/// Returns `None` for dtypes this function doesn't decode (e.g. quantized
+/// packed integers), rather than an error, since that's an expected,
+/// common case rather than a failure.
+pub async fn compute_tensor_stats(
+    source: &dyn MetadataSource,
+    tensor: &TensorRef,
+) -> Result<Option<TensorStats>> {
+    let element_size = match element_size(tensor.dtype) {
+        Some(size) => size,
+        None => return Ok(None),
+    };
+
+    let [data_start, data_end] = tensor.data_offsets;
+
+    let sampled = (data_end - data_start) as u64 > MAX_PREVIEW_BYTES;
+    let fetch_end = if sampled {
+        data_start + MAX_PREVIEW_BYTES as usize
+    } else {
+        data_end
+    };
+    // Only decode whole elements out of a sampled window.
+    let fetch_end = data_start + (fetch_end - data_start) / element_size * element_size;
+
+    let header_end = 8 + tensor.header_size;
+    let range = (header_end + data_start as u64)..(header_end + fetch_end as u64);
+    let bytes = source.read_tensor_bytes(&tensor.checkpoint, range).await?;
+
+    let values = match decode(tensor.dtype, &bytes) {
+        Some(values) => values,
+        None => return Ok(None),
+    };
+
+    Ok(Some(summarize(&values, sampled)))
+}
+
+/// Size in bytes of a single element of `dtype`, for dtypes this module
+/// knows how to decode into plain numeric values. `None` for
+/// packed/quantized or otherwise opaque dtypes.
+pub(crate) fn element_size(dtype: Dtype) -> Option<usize> {
+    match dtype {
+        Dtype::BOOL | Dtype::U8 | Dtype::I8 => Some(1),
+        Dtype::I16 | Dtype::U16 | Dtype::F16 | Dtype::BF16 => Some(2),
+        Dtype::I32 | Dtype::U32 | Dtype::F32 => Some(4),
+        Dtype::I64 | Dtype::U64 | Dtype::F64 => Some(8),
+        _ => None,
+    }
+}
+
+pub(crate) fn decode(dtype: Dtype, bytes: &[u8]) -> Option<Vec<f64>> {
+    let element_size = element_size(dtype)?;
+    let values = bytes.chunks_exact(element_size).map(|chunk| match dtype {
+        Dtype::BOOL => (chunk[0] != 0) as u8 as f64,
+        Dtype::U8 => chunk[0] as f64,
+        Dtype::I8 => chunk[0] as i8 as f64,
+        Dtype::I16 => i16::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::U16 => u16::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::F16 => f16_to_f32(u16::from_le_bytes(chunk.try_into().unwrap())) as f64,
+        Dtype::BF16 => bf16_to_f32(u16::from_le_bytes(chunk.try_into().unwrap())) as f64,
+        Dtype::I32 => i32::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::U32 => u32::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::F32 => f32::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::I64 => i64::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::U64 => u64::from_le_bytes(chunk.try_into().unwrap()) as f64,
+        Dtype::F64 => f64::from_le_bytes(chunk.try_into().unwrap()),
+        _ => unreachable!("element_size returned Some for an undecodable dtype"),
+    });
+
+    Some(values.collect())
+}
+
+/// IEEE-754 binary16 to `f32`.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = (bits >> 15) & 1;
+    let exponent = (bits >> 10) & 0x1F;
+    let fraction = bits & 0x3FF;
+
+    let magnitude = if exponent == 0 {
+        (fraction as f32) * 2f32.powi(-24)
+    } else if exponent == 0x1F {
+        if fraction == 0 {
+            f32::INFINITY
+        } else {
+            f32::NAN
+        }
+    } else {
+        (1.0 + fraction as f32 / 1024.0) * 2f32.powi(exponent as i32 - 15)
+    };
+
+    if sign == 1 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// bfloat16 to `f32`: the upper 16 bits of an `f32` are exactly a bfloat16,
+/// so decoding is a left shift into the high half.
+fn bf16_to_f32(bits: u16) -> f32 {
+    f32::from_bits((bits as u32) << 16)
+}
+
+fn summarize(values: &[f64], sampled: bool) -> TensorStats {
+    let count = values.len();
+    let nan_count = values.iter().filter(|v| v.is_nan()).count();
+    let inf_count = values.iter().filter(|v| v.is_infinite()).count();
+
+    // NaN/Inf are reported separately rather than folded into min/max/mean/
+    // std/histogram, where a handful of corrupted values would otherwise
+    // poison the whole summary (e.g. any NaN propagating through mean).
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let finite_count = finite.len();
+
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    // Welford's online algorithm for mean/variance in one pass.
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    for (i, &value) in finite.iter().enumerate() {
+        let delta = value - mean;
+        mean += delta / (i + 1) as f64;
+        m2 += delta * (value - mean);
+    }
+    let std = if finite_count > 1 {
+        (m2 / finite_count as f64).sqrt()
+    } else {
+        0.0
+    };
+
+    let mut histogram = vec![0; HISTOGRAM_BINS];
+    let range = max - min;
+    if range > 0.0 {
+        for &value in &finite {
+            let bin = (((value - min) / range) * HISTOGRAM_BINS as f64) as usize;
+            histogram[bin.min(HISTOGRAM_BINS - 1)] += 1;
+        }
+    } else if finite_count > 0 {
+        histogram[0] = finite_count;
+    }
+
+    TensorStats {
+        count,
+        min,
+        max,
+        mean,
+        std,
+        histogram,
+        nan_count,
+        inf_count,
+        sampled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::summarize;
+
+    #[test]
+    fn test_summarize_excludes_nan_and_inf() {
+        let values = [1.0, 2.0, 3.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+        let stats = summarize(&values, false);
+
+        assert_eq!(stats.count, 6);
+        assert_eq!(stats.nan_count, 1);
+        assert_eq!(stats.inf_count, 2);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 3.0);
+        assert_eq!(stats.mean, 2.0);
+        assert_eq!(stats.histogram.iter().sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_summarize_all_nan_has_no_finite_values() {
+        let values = [f64::NAN, f64::NAN];
+        let stats = summarize(&values, false);
+
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.nan_count, 2);
+        assert_eq!(stats.inf_count, 0);
+        assert_eq!(stats.min, f64::INFINITY);
+        assert_eq!(stats.max, f64::NEG_INFINITY);
+        assert_eq!(stats.histogram.iter().sum::<usize>(), 0);
+    }
+
+    #[test]
+    fn test_summarize_single_finite_value_has_zero_std() {
+        let values = [5.0, f64::NAN];
+        let stats = summarize(&values, false);
+
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.std, 0.0);
+        assert_eq!(stats.histogram[0], 1);
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::config::Config;
+use crate::metadata::TensorMetadata;
+use crate::models::{block_index, get_param_layer};
+use crate::stats::element_size;
+
+/// An at-a-glance profile of a checkpoint: how many parameters it has,
+/// where their bytes go by dtype, and how they're distributed across
+/// layer types and transformer blocks.
+pub struct ModelSummary {
+    pub total_params: u64,
+    pub total_bytes: u64,
+    pub bytes_by_dtype: BTreeMap<String, u64>,
+    pub params_by_layer_type: BTreeMap<String, u64>,
+    pub params_by_block: BTreeMap<usize, u64>,
+}
+
+pub fn summarize(tensors: &HashMap<String, TensorMetadata>, config: &Config) -> ModelSummary {
+    let param_to_layer = get_param_layer(&config.model_type);
+
+    let mut total_params = 0;
+    let mut total_bytes = 0;
+    let mut bytes_by_dtype = BTreeMap::new();
+    let mut params_by_layer_type = BTreeMap::new();
+    let mut params_by_block = BTreeMap::new();
+
+    for tensor in tensors.values() {
+        let raw_count = tensor.tensor_info.shape.iter().product::<usize>() as u64;
+        let bytes = element_size(tensor.tensor_info.dtype).unwrap_or(0) as u64 * raw_count;
+        let count = param_count(tensor, raw_count);
+
+        total_params += count;
+        total_bytes += bytes;
+        *bytes_by_dtype
+            .entry(format!("{:?}", tensor.tensor_info.dtype))
+            .or_insert(0) += bytes;
+
+        let layer_type = param_to_layer
+            .as_ref()
+            .and_then(|param_to_layer| param_to_layer.param_to_layer(&tensor.name))
+            .unwrap_or("Other");
+        *params_by_layer_type
+            .entry(layer_type.to_string())
+            .or_insert(0) += count;
+
+        if let Some(block) = block_index(&tensor.name) {
+            *params_by_block.entry(block).or_insert(0) += count;
+        }
+    }
+
+    ModelSummary {
+        total_params,
+        total_bytes,
+        bytes_by_dtype,
+        params_by_layer_type,
+        params_by_block,
+    }
+}
+
+/// The logical parameter count of `tensor`, correcting for packing on a
+/// quantized weight tensor: its stored shape is the *packed* storage shape,
+/// so `raw_count` (used as-is for byte totals, which are correct as storage
+/// sizes) undercounts the true element count by a factor of
+/// [`crate::metadata::QuantizedDType::n_packed`]. Falls back to `raw_count`
+/// for unquantized tensors and for a quantized tensor's zero-points/scales,
+/// which aren't themselves model parameters. Which tensor name holds the
+/// weight is scheme-dependent (`qweight` for AWQ/GPTQ, `weight` for
+/// FP8/bitsandbytes), so the dispatch lives on the quantization itself
+/// rather than a hardcoded name check here.
+fn param_count(tensor: &TensorMetadata, raw_count: u64) -> u64 {
+    tensor
+        .quantization
+        .as_ref()
+        .and_then(|quantization| {
+            quantization.dequantized_param_count(&tensor.name, &tensor.tensor_info.shape)
+        })
+        .unwrap_or(raw_count)
+}